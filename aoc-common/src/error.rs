@@ -0,0 +1,147 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Error produced when puzzle input doesn't match the expected format.
+///
+/// Line and column numbers start at 1. Errors are displayed as `line:column: expected ...`, so
+/// prefixing them with the input's name gives the familiar `input.txt:17:4: expected ...`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// Error for input which ended while `expected` was still required.
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(input.lines().count() + 1, 1, expected)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of puzzle input, which knows its line number so that errors can be reported at a
+/// position within it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Iterates the lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(idx, text)| Line {
+        number: idx + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, column, expected)
+    }
+
+    /// Column at which `token` starts. `token` must be a slice of this line's text.
+    pub fn column_of(&self, token: &str) -> usize {
+        let offset = token.as_ptr() as usize - self.text.as_ptr() as usize;
+        assert!(
+            offset <= self.text.len(),
+            "token must be a slice of the line"
+        );
+        self.text[..offset].chars().count() + 1
+    }
+
+    /// Error reported at the start of `token`, which must be a slice of this line's text.
+    pub fn error_at(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        self.error(self.column_of(token), expected)
+    }
+
+    /// Error reported just past the last character of the line.
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error(self.text.chars().count() + 1, expected)
+    }
+
+    /// Returns `token`, or an error at the end of the line if there is no token.
+    pub fn expect(
+        &self,
+        token: Option<&'a str>,
+        expected: impl Into<String>,
+    ) -> Result<&'a str, ParseError> {
+        token.ok_or_else(|| self.error_at_end(expected))
+    }
+
+    /// Parses `token`, which must be a slice of this line's text, reporting an error at its
+    /// position if it is not valid.
+    pub fn parse<T: FromStr>(
+        &self,
+        token: &str,
+        expected: impl Into<String>,
+    ) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error_at(token, expected))
+    }
+
+    /// Strips `prefix` from the start of the line, returning the rest of the line. Leading and
+    /// trailing whitespace is left out of the error message.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(1, format!("'{}'", prefix.trim())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lines, ParseError};
+
+    #[test]
+    fn display() {
+        let err = ParseError::new(17, 4, "'addx' or 'noop'");
+        assert_eq!(err.to_string(), "17:4: expected 'addx' or 'noop'");
+    }
+
+    #[test]
+    fn line_positions() {
+        let input = "first\nnoop 12\n";
+        let line = lines(input).nth(1).unwrap();
+        assert_eq!(line.number, 2);
+        let token = line.text.split(' ').nth(1).unwrap();
+        assert_eq!(line.column_of(token), 6);
+        assert_eq!(line.parse::<u32>(token, "an integer"), Ok(12),);
+        assert_eq!(
+            line.parse::<u32>(&line.text[..4], "an integer"),
+            Err(ParseError::new(2, 1, "an integer"))
+        );
+        assert_eq!(line.error_at_end("x"), ParseError::new(2, 8, "x"));
+        assert_eq!(
+            ParseError::end_of_input(input, "x"),
+            ParseError::new(3, 1, "x")
+        );
+        assert_eq!(line.strip_prefix("noop "), Ok("12"));
+        assert_eq!(
+            line.strip_prefix("addx "),
+            Err(ParseError::new(2, 1, "'addx'"))
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! 2D geometry shared by the days whose puzzles take place on a plane.
+//!
+//! `x` increases to the right and `y` increases downwards, which matches the way grids are laid
+//! out in puzzle input. "Up" is therefore towards negative `y`, and rotations are clockwise as
+//! seen on screen.
+
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign},
+};
+
+/// Signed integer type which can be used as a coordinate of a [`Vec2`].
+pub trait Coord:
+    'static
+    + Copy
+    + Debug
+    + Default
+    + Eq
+    + Ord
+    + Hash
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+    const NEG_ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+
+    /// Widened to the largest integer type, for arithmetic which mustn't overflow.
+    fn to_i128(self) -> i128;
+    /// Narrowed from the largest integer type, or `None` if `n` is out of range.
+    fn from_i128(n: i128) -> Option<Self>;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const TWO: Self = 2;
+                const NEG_ONE: Self = -1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(n: i128) -> Option<Self> {
+                    <$t>::try_from(n).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize);
+
+/// 2D vector, used both for positions and the offsets between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Vec2<T> {
+        Vec2 { x, y }
+    }
+}
+
+impl<T: Coord> Vec2<T> {
+    pub const ZERO: Vec2<T> = Vec2::new(T::ZERO, T::ZERO);
+
+    pub const UP: Vec2<T> = Vec2::new(T::ZERO, T::NEG_ONE);
+    pub const DOWN: Vec2<T> = Vec2::new(T::ZERO, T::ONE);
+    pub const LEFT: Vec2<T> = Vec2::new(T::NEG_ONE, T::ZERO);
+    pub const RIGHT: Vec2<T> = Vec2::new(T::ONE, T::ZERO);
+    pub const UP_LEFT: Vec2<T> = Vec2::new(T::NEG_ONE, T::NEG_ONE);
+    pub const UP_RIGHT: Vec2<T> = Vec2::new(T::ONE, T::NEG_ONE);
+    pub const DOWN_LEFT: Vec2<T> = Vec2::new(T::NEG_ONE, T::ONE);
+    pub const DOWN_RIGHT: Vec2<T> = Vec2::new(T::ONE, T::ONE);
+
+    /// Directions to the 4 orthogonally adjacent positions, clockwise from up.
+    pub const ORTHOGONAL: [Vec2<T>; 4] = [Vec2::UP, Vec2::RIGHT, Vec2::DOWN, Vec2::LEFT];
+    /// Directions to the 8 adjacent positions, including diagonals, clockwise from up.
+    pub const ADJACENT: [Vec2<T>; 8] = [
+        Vec2::UP,
+        Vec2::UP_RIGHT,
+        Vec2::RIGHT,
+        Vec2::DOWN_RIGHT,
+        Vec2::DOWN,
+        Vec2::DOWN_LEFT,
+        Vec2::LEFT,
+        Vec2::UP_LEFT,
+    ];
+
+    /// Sum of the absolute differences of the coordinates.
+    pub fn manhattan_distance(self, other: Vec2<T>) -> T {
+        let diff = self - other;
+        diff.x.abs() + diff.y.abs()
+    }
+
+    /// Largest absolute difference of the coordinates, i.e. the number of king's moves between
+    /// the positions.
+    pub fn chebyshev_distance(self, other: Vec2<T>) -> T {
+        let diff = self - other;
+        diff.x.abs().max(diff.y.abs())
+    }
+
+    /// Component-wise signum: the single step (possibly diagonal) which moves towards this
+    /// offset.
+    pub fn signum(self) -> Vec2<T> {
+        Vec2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    /// Rotated by 90 degrees clockwise about the origin, e.g. `RIGHT` becomes `DOWN`.
+    pub fn rotate_cw(self) -> Vec2<T> {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// Rotated by 90 degrees anticlockwise about the origin, e.g. `DOWN` becomes `RIGHT`.
+    pub fn rotate_ccw(self) -> Vec2<T> {
+        Vec2::new(self.y, -self.x)
+    }
+
+    /// The 4 orthogonally adjacent positions, clockwise from up.
+    pub fn neighbours4(self) -> [Vec2<T>; 4] {
+        Vec2::ORTHOGONAL.map(|direction| self + direction)
+    }
+
+    /// The 8 adjacent positions, including diagonals, clockwise from up.
+    pub fn neighbours8(self) -> [Vec2<T>; 8] {
+        Vec2::ADJACENT.map(|direction| self + direction)
+    }
+}
+
+impl<T: Coord> Add for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, other: Vec2<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord> Sub for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coord> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, other: Vec2<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Coord> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn neg(self) -> Vec2<T> {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+/// Scales both coordinates.
+impl<T: Coord> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn mul(self, scale: T) -> Vec2<T> {
+        Vec2::new(self.x * scale, self.y * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Coord, Vec2};
+
+    #[test]
+    fn operators() {
+        let a = Vec2::new(3, -2);
+        let b = Vec2::new(-1, 5);
+        assert_eq!(a + b, Vec2::new(2, 3));
+        assert_eq!(a - b, Vec2::new(4, -7));
+        assert_eq!(-a, Vec2::new(-3, 2));
+        assert_eq!(a * 3, Vec2::new(9, -6));
+        let mut c = a;
+        c += b;
+        c -= Vec2::RIGHT;
+        assert_eq!(c, Vec2::new(1, 3));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2::new(3i64, -2);
+        let b = Vec2::new(-1, 5);
+        assert_eq!(a.manhattan_distance(b), 11);
+        assert_eq!(a.chebyshev_distance(b), 7);
+        assert_eq!((b - a).signum(), Vec2::new(-1, 1));
+        assert_eq!(Vec2::<i32>::ZERO.signum(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(i32::MIN.to_i128(), -(1 << 31));
+        assert_eq!(i64::from_i128(6_000_000_000), Some(6_000_000_000));
+        assert_eq!(i32::from_i128(6_000_000_000), None);
+    }
+
+    #[test]
+    fn rotation() {
+        let mut direction = Vec2::<i32>::UP;
+        for expected in [Vec2::RIGHT, Vec2::DOWN, Vec2::LEFT, Vec2::UP] {
+            direction = direction.rotate_cw();
+            assert_eq!(direction, expected);
+        }
+        assert_eq!(Vec2::new(2, 1).rotate_ccw(), Vec2::new(1, -2));
+        assert_eq!(Vec2::new(2, 1).rotate_cw().rotate_ccw(), Vec2::new(2, 1));
+    }
+
+    #[test]
+    fn neighbours() {
+        let pos = Vec2::new(5, 5);
+        assert_eq!(
+            pos.neighbours4(),
+            [(5, 4), (6, 5), (5, 6), (4, 5)].map(|(x, y)| Vec2::new(x, y))
+        );
+        assert_eq!(pos.neighbours8().len(), 8);
+        assert!(pos
+            .neighbours8()
+            .iter()
+            .all(|&n| pos.chebyshev_distance(n) == 1));
+    }
+}
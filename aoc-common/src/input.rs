@@ -0,0 +1,126 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    process,
+};
+
+/// Where puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        };
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+/// `-` means stdin, anything else is a file path.
+impl From<&str> for InputSource {
+    fn from(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: InputSource,
+    error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.source, self.error.kind()) {
+            (InputSource::File(path), io::ErrorKind::NotFound) => {
+                write!(f, "input file '{}' does not exist", path.display())
+            }
+            (source, _) => write!(f, "could not read input from {source}: {}", self.error),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Reads the puzzle input named by the first command line argument (a file path, or `-` for
+/// stdin), falling back to `default_path` when no argument is given. Exits the process with an
+/// error message if the input cannot be read.
+pub fn read_from_args(default_path: &str) -> String {
+    source_from_args(default_path)
+        .read()
+        .unwrap_or_else(|err| exit_with_error(&err.to_string()))
+}
+
+/// The input source named by the first command line argument, or `default_path` when no argument
+/// is given.
+pub fn source_from_args(default_path: &str) -> InputSource {
+    let mut args = env::args().skip(1);
+    let source = InputSource::from(args.next().as_deref().unwrap_or(default_path));
+    if args.next().is_some() {
+        exit_with_error("expected at most one argument: the input file path, or - for stdin");
+    }
+    source
+}
+
+/// Prints `message` as an error and exits the process.
+pub fn exit_with_error(message: &str) -> ! {
+    eprintln!("error: {message}");
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::InputSource;
+
+    #[test]
+    fn source_from_arg() {
+        assert_eq!(InputSource::from("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from("input.txt"),
+            InputSource::File(PathBuf::from("input.txt"))
+        );
+    }
+
+    #[test]
+    fn missing_file() {
+        let err = InputSource::from("does/not/exist.txt").read().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input file 'does/not/exist.txt' does not exist"
+        );
+    }
+}
@@ -0,0 +1,585 @@
+//! Sets of integers stored as sorted, disjoint ranges, for puzzles which deal in spans of
+//! positions or IDs too large to store one value at a time.
+//!
+//! ```
+//! use aoc_common::interval::RangeSet;
+//!
+//! let covered: RangeSet = [0..5, 3..8, 10..12].into_iter().collect();
+//! assert_eq!(covered.ranges(), [0..8, 10..12]);
+//! assert!(covered.contains(7) && !covered.contains(8));
+//! assert_eq!(covered.gaps().collect::<Vec<_>>(), [8..10]);
+//! assert_eq!(covered.size(), 10);
+//! ```
+
+use std::ops::Range;
+
+use crate::geometry::Coord;
+
+/// Comparisons and combinations of two half-open ranges.
+pub trait RangeOps<T> {
+    fn overlaps(&self, other: &Self) -> bool;
+    fn touches(&self, other: &Self) -> bool;
+    fn after(&self, other: &Self) -> bool;
+    fn before(&self, other: &Self) -> bool {
+        other.after(self)
+    }
+    /// Whether every value in `other` is also in this range. Empty ranges are contained in
+    /// every range.
+    fn contains_range(&self, other: &Self) -> bool;
+    fn truncate_after(&self, truncate_at: T) -> Self;
+    fn truncate_before(&self, truncate_at: T) -> Self;
+    fn intersection(&self, other: &Self) -> Self;
+    /// Smallest range containing both ranges, including any values between them.
+    fn union(&self, other: &Self) -> Self;
+    fn size(&self) -> T;
+}
+
+impl<T: Coord> RangeOps<T> for Range<T> {
+    fn overlaps(&self, other: &Range<T>) -> bool {
+        self.start < other.end && self.end > other.start
+    }
+
+    fn touches(&self, other: &Range<T>) -> bool {
+        self.start <= other.end && self.end >= other.start
+    }
+
+    fn after(&self, other: &Range<T>) -> bool {
+        self.start >= other.end
+    }
+
+    fn contains_range(&self, other: &Range<T>) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    fn truncate_after(&self, truncate_at_exclusive: T) -> Range<T> {
+        Range {
+            start: self.start,
+            end: self.end.min(truncate_at_exclusive),
+        }
+    }
+
+    fn truncate_before(&self, truncate_at_inclusive: T) -> Range<T> {
+        Range {
+            start: self.start.max(truncate_at_inclusive),
+            end: self.end,
+        }
+    }
+
+    fn intersection(&self, other: &Range<T>) -> Range<T> {
+        Range {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        }
+    }
+
+    fn union(&self, other: &Range<T>) -> Range<T> {
+        Range {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    fn size(&self) -> T {
+        self.end - self.start
+    }
+}
+
+/// Set of integers, stored as the ranges of consecutive values it contains. The ranges are
+/// sorted, none of them are empty, and no two of them touch, so each set has exactly one
+/// representation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RangeSet<T = i32> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Coord> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Coord> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    /// The ranges of consecutive values in the set, in ascending order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn size(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |size, range| size + range.size())
+    }
+
+    pub fn add_range(&mut self, range_to_add: &Range<T>) {
+        if range_to_add.is_empty() {
+            return;
+        }
+        // Ranges are sorted and never touch each other, so the ranges which touch `range_to_add`
+        // are consecutive. Find the first range which touches it, and the first range after it
+        // which doesn't.
+        let touching_start_idx = self
+            .ranges
+            .partition_point(|range| range.end < range_to_add.start);
+        let touching_end_idx = self
+            .ranges
+            .partition_point(|range| range.start <= range_to_add.end);
+        if touching_start_idx == touching_end_idx {
+            // There are no touching ranges. Add this range to the list
+            self.ranges.insert(touching_start_idx, range_to_add.clone());
+        } else {
+            let merged = range_to_add
+                .union(&self.ranges[touching_start_idx])
+                .union(&self.ranges[touching_end_idx - 1]);
+            self.ranges
+                .splice(touching_start_idx..touching_end_idx, [merged]);
+        }
+    }
+
+    pub fn subtract_range(&mut self, range_to_subtract: &Range<T>) {
+        if range_to_subtract.is_empty() {
+            return;
+        }
+        // As in `add_range`, the ranges which overlap `range_to_subtract` are consecutive. Only
+        // the first and last of them can stick out of it, and they are truncated rather than
+        // removed.
+        let overlapping_start_idx = self
+            .ranges
+            .partition_point(|range| range.end <= range_to_subtract.start);
+        let overlapping_end_idx = self
+            .ranges
+            .partition_point(|range| range.start < range_to_subtract.end);
+        if overlapping_start_idx == overlapping_end_idx {
+            return;
+        }
+        let left = self.ranges[overlapping_start_idx].truncate_after(range_to_subtract.start);
+        let right = self.ranges[overlapping_end_idx - 1].truncate_before(range_to_subtract.end);
+        self.ranges.splice(
+            overlapping_start_idx..overlapping_end_idx,
+            [left, right].into_iter().filter(|range| !range.is_empty()),
+        );
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.range_containing(value).is_some()
+    }
+
+    /// The range of consecutive values in the set which `value` belongs to, if it is in the set.
+    pub fn range_containing(&self, value: T) -> Option<&Range<T>> {
+        let idx = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(idx).filter(|range| range.contains(&value))
+    }
+
+    /// Whether every value in `range` is in the set.
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        range.is_empty()
+            || self
+                .range_containing(range.start)
+                .is_some_and(|containing| containing.contains_range(range))
+    }
+
+    /// Whether every value in the set is also in `other`.
+    pub fn is_subset(&self, other: &RangeSet<T>) -> bool {
+        self.ranges.iter().all(|range| other.contains_range(range))
+    }
+
+    pub fn is_superset(&self, other: &RangeSet<T>) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Whether any value is in both sets.
+    pub fn overlaps(&self, other: &RangeSet<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Values in either set.
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut union: Vec<Range<T>> = Vec::with_capacity(self.ranges.len() + other.ranges.len());
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        // Merge the sorted ranges, joining each one onto the last if they touch
+        while let Some(range) = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) if y.start < x.start => b.next(),
+            (Some(_), _) => a.next(),
+            (None, _) => b.next(),
+        } {
+            match union.last_mut() {
+                Some(last) if last.touches(range) => *last = last.union(range),
+                _ => union.push(range.clone()),
+            }
+        }
+        RangeSet { ranges: union }
+    }
+
+    /// Values in both sets.
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut intersection = Vec::new();
+        let (mut a, mut b) = (0, 0);
+        while a < self.ranges.len() && b < other.ranges.len() {
+            let overlap = self.ranges[a].intersection(&other.ranges[b]);
+            if !overlap.is_empty() {
+                intersection.push(overlap);
+            }
+            // The range which ends first can't overlap any later range of the other set
+            if self.ranges[a].end < other.ranges[b].end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        RangeSet {
+            ranges: intersection,
+        }
+    }
+
+    /// Values in this set but not in `other`.
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => {
+                self.intersection(&other.complement(&(first.start..last.end)))
+            }
+            _ => RangeSet::new(),
+        }
+    }
+
+    /// Values in exactly one of the sets.
+    pub fn symmetric_difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        self.difference(other).union(&other.difference(self))
+    }
+
+    /// Values in `universe` which aren't in the set.
+    pub fn complement(&self, universe: &Range<T>) -> RangeSet<T> {
+        let mut complement = RangeSet::new();
+        complement.add_range(universe);
+        for range in &self.ranges {
+            complement.subtract_range(range);
+        }
+        complement
+    }
+
+    /// Ranges of values between consecutive ranges of the set, in ascending order.
+    pub fn gaps(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges
+            .windows(2)
+            .map(|pair| pair[0].end..pair[1].start)
+    }
+
+    /// Every value in the set, in ascending order.
+    pub fn values(&self) -> impl Iterator<Item = T> + '_ {
+        self.ranges.iter().flat_map(|range| {
+            std::iter::successors(Some(range.start), |&value| Some(value + T::ONE))
+                .take_while(move |&value| value < range.end)
+        })
+    }
+}
+
+impl<T: Coord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut range_set = RangeSet::new();
+        range_set.extend(ranges);
+        range_set
+    }
+}
+
+impl<T: Coord> Extend<Range<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.add_range(&range);
+        }
+    }
+}
+
+/// Sets of single values.
+impl<T: Coord> FromIterator<T> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(values: I) -> Self {
+        values
+            .into_iter()
+            .map(|value| value..value + T::ONE)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, ops::Range};
+
+    use proptest::prelude::*;
+
+    use super::{RangeOps, RangeSet};
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_range() {
+        assert!((3..4).after(&(2..3)));
+        assert!((2..3).before(&(3..4)));
+        assert!(!(2..3).overlaps(&(3..4)));
+        assert!((2..3).overlaps(&(2..4)));
+        assert!((2..3).touches(&(3..4)));
+        assert!(!(2..3).touches(&(4..5)));
+        assert!(!(-100..-51).before(&(-75..4)));
+        assert!(!(-75..4).after(&(-100..-51)));
+        assert_eq!((0..100).truncate_after(50), 0..50);
+        assert_eq!((0..100).truncate_before(50), 50..100);
+        assert_eq!((0..100).truncate_after(100), 0..100);
+        assert_eq!((0..100).truncate_before(0), 0..100);
+        assert!((0..100).truncate_after(-100).is_empty());
+        assert_eq!((0..100).truncate_after(-100), 0..-100);
+        assert!((0..100).truncate_before(200).is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init, clippy::reversed_empty_ranges)]
+    fn test_range_set() {
+        // Test empty RangeSet
+        let mut range_set = RangeSet::new();
+        assert!(range_set.is_empty());
+
+        // Test adding first range
+        range_set.add_range(&(2..3));
+        assert_eq!(range_set.ranges(), [2..3]);
+
+        // Test adding non touching range at end
+        range_set.add_range(&(4..5));
+        assert_eq!(range_set.ranges(), [2..3, 4..5]);
+
+        // Test adding range which causes all existing ranges to be merged
+        range_set.add_range(&(3..4));
+        assert_eq!(range_set.ranges(), [2..5]);
+
+        // Test adding redundant range
+        range_set.add_range(&(2..4));
+        assert_eq!(range_set.ranges(), [2..5]);
+
+        // Test adding redundant empty range
+        range_set.add_range(&(10..-10));
+        assert_eq!(range_set.ranges(), [2..5]);
+
+        // Test adding non touching range at start
+        range_set.add_range(&(-100..-51));
+        assert_eq!(range_set.ranges(), [-100..-51, 2..5]);
+
+        // Test adding non touching range in middle
+        range_set.add_range(&(-20..-15));
+        assert_eq!(range_set.ranges(), [-100..-51, -20..-15, 2..5]);
+
+        // Test adding ranges which extend the ranges they touch
+        range_set.add_range(&(-110..-90));
+        range_set.add_range(&(4..8));
+        assert_eq!(range_set.ranges(), [-110..-51, -20..-15, 2..8]);
+
+        // Undo the extensions above
+        range_set.subtract_range(&(-110..-100));
+        range_set.subtract_range(&(5..8));
+        assert_eq!(range_set.ranges(), [-100..-51, -20..-15, 2..5]);
+
+        // Test adding non touching ranges after and before all existing ranges, and a range which
+        // swallows several existing ranges
+        let mut other = RangeSet::new();
+        for range in [10..11, 20..21, 30..31, 0..1, 40..41, 15..32] {
+            other.add_range(&range);
+        }
+        assert_eq!(other.ranges(), [0..1, 10..11, 15..32, 40..41]);
+        other.add_range(&(-5..50));
+        assert_eq!(other.ranges(), [-5..50]);
+
+        // Test redundant subtraction after all existing groups
+        range_set.subtract_range(&(100..110));
+        assert_eq!(range_set.ranges(), [-100..-51, -20..-15, 2..5]);
+
+        // Test redundant subtraction before all existing groups
+        range_set.subtract_range(&(-1000..-999));
+        assert_eq!(range_set.ranges(), [-100..-51, -20..-15, 2..5]);
+
+        // Test redundant subtraction of range between existing ranges
+        range_set.subtract_range(&(0..1));
+        assert_eq!(range_set.ranges(), [-100..-51, -20..-15, 2..5]);
+
+        // Test redundant subtraction of empty range
+        range_set.subtract_range(&(10..-10));
+        assert_eq!(range_set.ranges(), [-100..-51, -20..-15, 2..5]);
+
+        // Test subtraction which truncates a left and right range, and removes a range in the middle
+        range_set.subtract_range(&(-75..4));
+        assert_eq!(range_set.ranges(), [-100..-75, 4..5]);
+
+        // Test subtraction in the middle of a range
+        range_set.subtract_range(&(-90..-85));
+        assert_eq!(range_set.ranges(), [-100..-90, -85..-75, 4..5]);
+
+        // Test subtraction which removes a range in the middle
+        range_set.subtract_range(&(-85..-75));
+        assert_eq!(range_set.ranges(), [-100..-90, 4..5]);
+
+        // Test subtraction which truncates left range only
+        range_set.subtract_range(&(-200..-95));
+        assert_eq!(range_set.ranges(), [-95..-90, 4..5]);
+
+        // Test subtraction which removes right range
+        range_set.subtract_range(&(0..100));
+        assert_eq!(range_set.ranges(), [-95..-90]);
+
+        // Test subtraction which removes last range
+        range_set.subtract_range(&(-1000..1000));
+        assert!(range_set.is_empty());
+
+        // Test redundant subtraction on empty range set
+        range_set.subtract_range(&(-1000..1000));
+        assert!(range_set.is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn set_algebra() {
+        let a: RangeSet = [0..5, 10..15, 20..25].into_iter().collect();
+        let b: RangeSet = [3..12, 24..30].into_iter().collect();
+        assert_eq!(a.union(&b).ranges(), [0..15, 20..30]);
+        assert_eq!(a.intersection(&b).ranges(), [3..5, 10..12, 24..25]);
+        assert_eq!(a.difference(&b).ranges(), [0..3, 12..15, 20..24]);
+        assert_eq!(b.difference(&a).ranges(), [5..10, 25..30]);
+        assert_eq!(
+            a.symmetric_difference(&b).ranges(),
+            [0..3, 5..10, 12..15, 20..24, 25..30]
+        );
+        assert_eq!(a.complement(&(-5..22)).ranges(), [-5..0, 5..10, 15..20]);
+        assert_eq!(a.complement(&(6..9)).ranges(), [6..9]);
+        assert!(a.complement(&(11..13)).is_empty());
+        assert_eq!(a.gaps().collect::<Vec<_>>(), [5..10, 15..20]);
+        assert_eq!(a.size(), 15);
+
+        let empty = RangeSet::new();
+        assert_eq!(a.union(&empty), a);
+        assert!(a.intersection(&empty).is_empty());
+        assert_eq!(a.difference(&empty), a);
+        assert!(empty.difference(&a).is_empty());
+        assert_eq!(empty.gaps().count(), 0);
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn queries() {
+        let a: RangeSet = [0..5, 10..15].into_iter().collect();
+        assert!(a.contains(0) && a.contains(4) && a.contains(10));
+        assert!(!a.contains(-1) && !a.contains(5) && !a.contains(15));
+        assert_eq!(a.range_containing(12), Some(&(10..15)));
+        assert_eq!(a.range_containing(7), None);
+        assert!(a.contains_range(&(1..4)) && a.contains_range(&(10..15)));
+        assert!(!a.contains_range(&(3..11)) && !a.contains_range(&(14..16)));
+
+        let b: RangeSet = [1..3, 12..15].into_iter().collect();
+        assert!(b.is_subset(&a) && a.is_superset(&b) && !a.is_subset(&b));
+        assert!(RangeSet::new().is_subset(&b));
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&[5..10].into_iter().collect()));
+
+        let values: RangeSet = [3, 1, 2, 7, 5, 6].into_iter().collect();
+        assert_eq!(values.ranges(), [1..4, 5..8]);
+        assert_eq!(values.values().collect::<Vec<_>>(), [1, 2, 3, 5, 6, 7]);
+    }
+
+    #[test]
+    fn ranges() {
+        assert!((0..10).contains_range(&(2..5)));
+        assert!((0..10).contains_range(&(0..10)));
+        assert!(!(0..10).contains_range(&(5..11)));
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 20..15;
+        assert!((0..10).contains_range(&empty));
+    }
+
+    /// Small ranges, possibly empty, so that they often touch and overlap.
+    fn range() -> impl Strategy<Value = Range<i32>> {
+        (-20..20, -2..10).prop_map(|(start, len)| start..start + len)
+    }
+
+    fn range_set() -> impl Strategy<Value = Vec<Range<i32>>> {
+        prop::collection::vec(range(), 0..6)
+    }
+
+    /// The values in `ranges`, one at a time.
+    fn model(ranges: &[Range<i32>]) -> BTreeSet<i32> {
+        ranges.iter().flat_map(|range| range.clone()).collect()
+    }
+
+    /// The set, checked to be in its canonical form, as a set of single values.
+    fn values(range_set: &RangeSet) -> BTreeSet<i32> {
+        for range in range_set.ranges() {
+            assert!(!range.is_empty(), "{range_set:?}");
+        }
+        for pair in range_set.ranges().windows(2) {
+            assert!(pair[0].end < pair[1].start, "{range_set:?}");
+        }
+        range_set.values().collect()
+    }
+
+    proptest! {
+        #[test]
+        fn matches_model(a in range_set(), b in range_set(), universe in range()) {
+            let (set_a, set_b): (RangeSet, RangeSet) =
+                (a.iter().cloned().collect(), b.iter().cloned().collect());
+            let (model_a, model_b) = (model(&a), model(&b));
+
+            prop_assert_eq!(values(&set_a), model_a.clone());
+            prop_assert_eq!(set_a.size() as usize, model_a.len());
+            prop_assert_eq!(
+                values(&set_a.union(&set_b)),
+                model_a.union(&model_b).copied().collect::<BTreeSet<_>>()
+            );
+            prop_assert_eq!(
+                values(&set_a.intersection(&set_b)),
+                model_a.intersection(&model_b).copied().collect::<BTreeSet<_>>()
+            );
+            prop_assert_eq!(
+                values(&set_a.difference(&set_b)),
+                model_a.difference(&model_b).copied().collect::<BTreeSet<_>>()
+            );
+            prop_assert_eq!(
+                values(&set_a.symmetric_difference(&set_b)),
+                model_a.symmetric_difference(&model_b).copied().collect::<BTreeSet<_>>()
+            );
+            prop_assert_eq!(
+                values(&set_a.complement(&universe)),
+                universe.clone().filter(|value| !model_a.contains(value)).collect::<BTreeSet<_>>()
+            );
+            prop_assert_eq!(set_a.is_subset(&set_b), model_a.is_subset(&model_b));
+            prop_assert_eq!(set_a.overlaps(&set_b), !model_a.is_disjoint(&model_b));
+            prop_assert_eq!(
+                set_a.contains_range(&universe),
+                universe.clone().all(|value| model_a.contains(&value))
+            );
+
+            let mut subtracted = set_a.clone();
+            for range in &b {
+                subtracted.subtract_range(range);
+            }
+            prop_assert_eq!(subtracted, set_a.difference(&set_b));
+        }
+
+        #[test]
+        fn points_and_gaps(a in range_set(), value in -25..35) {
+            let set_a: RangeSet = a.iter().cloned().collect();
+            let model_a = model(&a);
+            prop_assert_eq!(set_a.contains(value), model_a.contains(&value));
+            if let Some(range) = set_a.range_containing(value) {
+                prop_assert!(range.contains(&value));
+            }
+
+            let gaps: BTreeSet<i32> = set_a.gaps().flatten().collect();
+            let (min, max) = (model_a.first(), model_a.last());
+            let expected: BTreeSet<i32> = match (min, max) {
+                (Some(&min), Some(&max)) => (min..max).filter(|v| !model_a.contains(v)).collect(),
+                _ => BTreeSet::new(),
+            };
+            prop_assert_eq!(gaps, expected);
+        }
+    }
+}
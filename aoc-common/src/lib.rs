@@ -0,0 +1,12 @@
+//! Code shared by the solutions to every day's puzzle.
+
+pub mod error;
+pub mod geometry;
+pub mod input;
+pub mod interval;
+pub mod parse;
+pub mod solution;
+
+pub use error::ParseError;
+pub use geometry::Vec2;
+pub use solution::Solution;
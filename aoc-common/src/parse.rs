@@ -0,0 +1,418 @@
+//! Small parser-combinator toolkit for line based puzzle input.
+//!
+//! A parser is any function which takes a [`Cursor`] into a line, consumes some of it, and returns
+//! the parsed value or a [`ParseError`] at the position where the input didn't match. Primitive
+//! parsers such as [`int`] and [`literal`] are combined with [`or`], [`separated`], [`list`] and
+//! friends, or just called one after another:
+//!
+//! ```
+//! use aoc_common::parse::{self, int, literal, Cursor};
+//! use aoc_common::{error, ParseError};
+//!
+//! fn range(c: &mut Cursor) -> Result<(i32, i32), ParseError> {
+//!     let lower = int(c)?;
+//!     literal("-")(c)?;
+//!     Ok((lower, int(c)?))
+//! }
+//!
+//! let line = error::lines("2-4").next().unwrap();
+//! assert_eq!(parse::parse_line(line, range), Ok((2, 4)));
+//! ```
+//!
+//! When a parser fails the cursor may have been partially advanced. Combinators which try
+//! alternatives ([`or`], [`opt`]) restore the cursor before moving on.
+
+use std::str::FromStr;
+
+use crate::error::{self, Line, ParseError};
+
+/// Position within a line of input, and the part of the line which hasn't been parsed yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor<'a> {
+    line: Line<'a>,
+    rest: &'a str,
+}
+
+/// Function which parses a `T` from the start of a cursor, advancing past it.
+pub trait Parser<'a, T>: Fn(&mut Cursor<'a>) -> Result<T, ParseError> {}
+
+impl<'a, T, F: Fn(&mut Cursor<'a>) -> Result<T, ParseError>> Parser<'a, T> for F {}
+
+impl<'a> Cursor<'a> {
+    /// Cursor at the start of `line`.
+    pub fn new(line: Line<'a>) -> Cursor<'a> {
+        Cursor {
+            line,
+            rest: line.text,
+        }
+    }
+
+    pub fn line(&self) -> Line<'a> {
+        self.line
+    }
+
+    /// The part of the line which hasn't been parsed yet.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn column(&self) -> usize {
+        self.line.column_of(self.rest)
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    /// Error reported at the cursor's position.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.line.error(self.column(), expected)
+    }
+
+    /// Consumes the longest prefix whose characters all satisfy `predicate`, returning it.
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let len = self
+            .rest
+            .find(|c: char| !predicate(c))
+            .unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        taken
+    }
+}
+
+/// Parses `text`, failing unless it is found at the cursor.
+pub fn literal<'a, 's>(text: &'s str) -> impl Parser<'a, ()> + 's {
+    move |cursor: &mut Cursor<'a>| match cursor.rest.strip_prefix(text) {
+        Some(rest) => {
+            cursor.rest = rest;
+            Ok(())
+        }
+        None => Err(cursor.error(format!("'{}'", text.trim()))),
+    }
+}
+
+/// Parses an integer with an optional leading `-`.
+pub fn int<T: FromStr>(cursor: &mut Cursor) -> Result<T, ParseError> {
+    let start = *cursor;
+    let sign_len = usize::from(cursor.rest.starts_with('-'));
+    cursor.rest = &cursor.rest[sign_len..];
+    let digits = cursor.take_while(|c| c.is_ascii_digit());
+    let text = &start.rest[..sign_len + digits.len()];
+    text.parse().map_err(|_| start.error("an integer"))
+}
+
+/// Succeeds only at the end of the line.
+pub fn end_of_line(cursor: &mut Cursor) -> Result<(), ParseError> {
+    if cursor.is_at_end() {
+        Ok(())
+    } else {
+        Err(cursor.error("end of line"))
+    }
+}
+
+/// Runs `parser`, reporting `expected` instead of its error if it fails without getting past
+/// the cursor's position.
+pub fn label<'a, 's, T>(
+    parser: impl Parser<'a, T> + 's,
+    expected: &'s str,
+) -> impl Parser<'a, T> + 's {
+    move |cursor: &mut Cursor<'a>| {
+        let start = *cursor;
+        parser(cursor).map_err(|err| {
+            if err.column == start.column() {
+                start.error(expected)
+            } else {
+                err
+            }
+        })
+    }
+}
+
+/// Runs `parser`, then fails at the start of the parsed value unless it satisfies `predicate`.
+pub fn verify<'a, 's, T>(
+    parser: impl Parser<'a, T> + 's,
+    predicate: impl Fn(&T) -> bool + 's,
+    expected: &'s str,
+) -> impl Parser<'a, T> + 's {
+    move |cursor: &mut Cursor<'a>| {
+        let start = *cursor;
+        let value = parser(cursor)?;
+        if predicate(&value) {
+            Ok(value)
+        } else {
+            Err(start.error(expected))
+        }
+    }
+}
+
+/// Runs `parser`, or leaves the cursor where it was and returns `None` if `parser` fails.
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        let start = *cursor;
+        match parser(cursor) {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => {
+                *cursor = start;
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Runs `a`, or `b` if `a` fails. If both fail, the error which got furthest is reported, or
+/// both expectations if they failed at the same position.
+pub fn or<'a, T>(a: impl Parser<'a, T>, b: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |cursor: &mut Cursor<'a>| {
+        let start = *cursor;
+        let a_err = match a(cursor) {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        };
+        *cursor = start;
+        b(cursor).map_err(|b_err| match a_err.column.cmp(&b_err.column) {
+            std::cmp::Ordering::Greater => a_err,
+            std::cmp::Ordering::Less => b_err,
+            std::cmp::Ordering::Equal => ParseError::new(
+                b_err.line,
+                b_err.column,
+                format!("{} or {}", a_err.expected, b_err.expected),
+            ),
+        })
+    }
+}
+
+/// Parses one or more `item`s separated by `separator`.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        let mut items = vec![item(cursor)?];
+        loop {
+            let before_separator = *cursor;
+            if separator(cursor).is_err() {
+                *cursor = before_separator;
+                return Ok(items);
+            }
+            items.push(item(cursor)?);
+        }
+    }
+}
+
+/// Parses a possibly empty list of `item`s separated by `separator`, between `open` and `close`
+/// brackets. Lists nest when `item` itself parses a list.
+pub fn list<'a, T, O, S, C>(
+    open: impl Parser<'a, O>,
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, Vec<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        open(cursor)?;
+        let before_close = *cursor;
+        if close(cursor).is_ok() {
+            return Ok(Vec::new());
+        }
+        *cursor = before_close;
+        let mut items = vec![item(cursor)?];
+        loop {
+            let before_separator = *cursor;
+            match separator(cursor) {
+                Ok(_) => items.push(item(cursor)?),
+                Err(separator_err) => {
+                    *cursor = before_separator;
+                    return match close(cursor) {
+                        Ok(_) => Ok(items),
+                        Err(close_err) => Err(ParseError::new(
+                            close_err.line,
+                            close_err.column,
+                            format!("{} or {}", separator_err.expected, close_err.expected),
+                        )),
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// Parses the whole of `line` with `parser`.
+pub fn parse_line<'a, T>(line: Line<'a>, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    let mut cursor = Cursor::new(line);
+    let value = parser(&mut cursor)?;
+    end_of_line(&mut cursor)?;
+    Ok(value)
+}
+
+/// Parses every line of `input` with `parser`.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    parser: impl Parser<'a, T>,
+) -> Result<Vec<T>, ParseError> {
+    error::lines(input)
+        .map(|line| parse_line(line, &parser))
+        .collect()
+}
+
+/// Groups of consecutive non-blank lines, i.e. records separated by blank lines.
+pub fn records(input: &str) -> impl Iterator<Item = Vec<Line<'_>>> {
+    let mut lines = error::lines(input).peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|line| line.text.is_empty()).is_some() {}
+        let mut record = Vec::new();
+        while let Some(line) = lines.next_if(|line| !line.text.is_empty()) {
+            record.push(line);
+        }
+        (!record.is_empty()).then_some(record)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        int, label, list, literal, opt, or, parse_line, parse_lines, records, separated, verify,
+        Cursor,
+    };
+    use crate::error::{self, ParseError};
+
+    fn line(text: &str) -> error::Line<'_> {
+        error::lines(text).next().unwrap()
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(parse_line(line("-42"), int::<i32>), Ok(-42));
+        assert_eq!(parse_line(line("17"), int::<u8>), Ok(17));
+        assert_eq!(
+            parse_line(line("-"), int::<i32>),
+            Err(ParseError::new(1, 1, "an integer"))
+        );
+        assert_eq!(
+            parse_line(line("12x"), int::<i32>),
+            Err(ParseError::new(1, 3, "end of line"))
+        );
+    }
+
+    #[test]
+    fn literals_and_alternatives() {
+        let instr = or(literal("addx"), literal("noop"));
+        assert_eq!(parse_line(line("noop"), &instr), Ok(()));
+        assert_eq!(
+            parse_line(line("add"), &instr),
+            Err(ParseError::new(1, 1, "'addx' or 'noop'"))
+        );
+        let signed = |c: &mut Cursor| {
+            literal("addx ")(c)?;
+            int::<i32>(c)
+        };
+        // The alternative which got furthest is reported
+        assert_eq!(
+            parse_line(
+                line("addx y"),
+                or(signed, |c: &mut Cursor| literal("noop")(c).map(|_| 0))
+            ),
+            Err(ParseError::new(1, 6, "an integer"))
+        );
+        assert_eq!(
+            parse_line(line("ab"), |c: &mut Cursor| {
+                let a = opt(literal("a"))(c)?;
+                let x = opt(literal("x"))(c)?;
+                literal("b")(c)?;
+                Ok((a, x))
+            }),
+            Ok((Some(()), None))
+        );
+    }
+
+    #[test]
+    fn label_and_verify() {
+        let stack = label(
+            verify(int::<u32>, |&n| n > 0, "a positive number"),
+            "a stack",
+        );
+        assert_eq!(parse_line(line("3"), &stack), Ok(3));
+        assert_eq!(
+            parse_line(line("x"), &stack),
+            Err(ParseError::new(1, 1, "a stack"))
+        );
+        assert_eq!(
+            parse_line(
+                line("0"),
+                verify(int::<u32>, |&n| n > 0, "a positive number")
+            ),
+            Err(ParseError::new(1, 1, "a positive number"))
+        );
+    }
+
+    #[test]
+    fn separated_lists() {
+        let points = separated(
+            |c: &mut Cursor| {
+                let x = int::<i32>(c)?;
+                literal(",")(c)?;
+                Ok((x, int::<i32>(c)?))
+            },
+            literal(" -> "),
+        );
+        assert_eq!(
+            parse_line(line("498,4 -> 498,6"), &points),
+            Ok(vec![(498, 4), (498, 6)])
+        );
+        assert_eq!(
+            parse_line(line("498,4 -> 498"), &points),
+            Err(ParseError::new(1, 13, "','"))
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Value {
+        Integer(u32),
+        List(Vec<Value>),
+    }
+
+    fn value(cursor: &mut Cursor) -> Result<Value, ParseError> {
+        or(
+            |c: &mut Cursor| int(c).map(Value::Integer),
+            |c: &mut Cursor| {
+                list(literal("["), value, literal(","), literal("]"))(c).map(Value::List)
+            },
+        )(cursor)
+    }
+
+    #[test]
+    fn nested_lists() {
+        assert_eq!(
+            parse_line(line("[1,[],[2]]"), value),
+            Ok(Value::List(vec![
+                Value::Integer(1),
+                Value::List(vec![]),
+                Value::List(vec![Value::Integer(2)]),
+            ]))
+        );
+        assert_eq!(
+            parse_line(line("[1,[2]"), value),
+            Err(ParseError::new(1, 7, "',' or ']'"))
+        );
+        assert_eq!(
+            parse_line(line("[1,]"), value),
+            Err(ParseError::new(1, 4, "an integer or '['"))
+        );
+    }
+
+    #[test]
+    fn lines_and_records() {
+        assert_eq!(parse_lines("1\n2\n", int::<i32>), Ok(vec![1, 2]));
+        assert_eq!(
+            parse_lines("1\nx\n", int::<i32>),
+            Err(ParseError::new(2, 1, "an integer"))
+        );
+        let records: Vec<Vec<usize>> = records("a\nb\n\n\nc\n")
+            .map(|record| record.iter().map(|line| line.number).collect())
+            .collect();
+        assert_eq!(records, [vec![1, 2], vec![5]]);
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+use crate::{error::ParseError, input};
+
+/// A solution to a day's puzzle.
+///
+/// The puzzle input is parsed once, and the parsed input is shared by both parts. This allows
+/// answers to be asserted on in tests and compared across implementations without going through
+/// stdout.
+pub trait Solution {
+    /// Puzzle input after parsing.
+    type Input;
+    /// Answer to part 1.
+    type Answer1: Display;
+    /// Answer to part 2.
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Self::Answer1;
+    fn part_two(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Parses `input` and solves `part` (1 or 2) of the puzzle. Returns `None` for any other part.
+pub fn solve<S: Solution>(input: &str, part: u8) -> Result<Option<String>, ParseError> {
+    let input = S::parse(input)?;
+    Ok(match part {
+        1 => Some(S::part_one(&input).to_string()),
+        2 => Some(S::part_two(&input).to_string()),
+        _ => None,
+    })
+}
+
+/// Reads the puzzle input named on the command line (see [`input::read_from_args`]) and parses
+/// it. Exits the process with a diagnostic such as `input.txt:17:4: expected 'addx' or 'noop'`
+/// if the input is malformed.
+pub fn parse_from_args<S: Solution>(default_path: &str) -> S::Input {
+    let source = input::source_from_args(default_path);
+    let input = source
+        .read()
+        .unwrap_or_else(|err| input::exit_with_error(&err.to_string()));
+    S::parse(&input).unwrap_or_else(|err| input::exit_with_error(&format!("{source}:{err}")))
+}
+
+/// Parses the puzzle input named on the command line and prints the answers to both parts.
+pub fn run<S: Solution>(default_path: &str) {
+    print_answers::<S>(&parse_from_args::<S>(default_path));
+}
+
+/// Prints the answers to both parts of the puzzle.
+pub fn print_answers<S: Solution>(input: &S::Input) {
+    print_answer(1, S::part_one(input));
+    print_answer(2, S::part_two(input));
+}
+
+fn print_answer(part: u8, answer: impl Display) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        // Multi-line answers (e.g. rendered images) are easier to read starting on their own line
+        println!("Part {part}:\n{answer}");
+    } else {
+        println!("Part {part}: {answer}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve, Solution};
+    use crate::error::ParseError;
+
+    struct WordCount;
+
+    impl Solution for WordCount {
+        type Input = Vec<String>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+            if input.is_empty() {
+                return Err(ParseError::new(1, 1, "a word"));
+            }
+            Ok(input.split_whitespace().map(str::to_string).collect())
+        }
+
+        fn part_one(words: &Vec<String>) -> usize {
+            words.len()
+        }
+
+        fn part_two(words: &Vec<String>) -> String {
+            words.join("-")
+        }
+    }
+
+    #[test]
+    fn solve_parts() {
+        assert_eq!(solve::<WordCount>("a b c", 1), Ok(Some("3".to_string())));
+        assert_eq!(
+            solve::<WordCount>("a b c", 2),
+            Ok(Some("a-b-c".to_string()))
+        );
+        assert_eq!(solve::<WordCount>("a b c", 3), Ok(None));
+        assert_eq!(
+            solve::<WordCount>("", 1),
+            Err(ParseError::new(1, 1, "a word"))
+        );
+    }
+}
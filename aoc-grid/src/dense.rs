@@ -0,0 +1,260 @@
+use std::ops::{Index, IndexMut};
+
+use aoc_common::{error, ParseError};
+
+use crate::Pos;
+
+/// Grid storing a value for every position in a `width * height` rectangle, with its top left
+/// corner at the origin. Cells are stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseGrid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> DenseGrid<T> {
+    /// Grid where every cell is `value`.
+    pub fn new(width: usize, height: usize, value: T) -> DenseGrid<T>
+    where
+        T: Clone,
+    {
+        DenseGrid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Grid made from `cells` stored row by row. Panics if `cells` can't be split into rows of
+    /// `width` cells.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> DenseGrid<T> {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert!(
+            height * width == cells.len(),
+            "{} cells can't be split into rows of {width}",
+            cells.len()
+        );
+        DenseGrid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses a grid with one row per line and one cell per character. `cell` converts each
+    /// character to a cell, returning `None` if the character isn't valid, in which case an error
+    /// saying that `expected` was expected is reported at that character.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<DenseGrid<T>, ParseError> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::new();
+        for line in error::lines(input) {
+            let row_start = cells.len();
+            for (col_idx, c) in line.text.chars().enumerate() {
+                if col_idx == width {
+                    return Err(line.error(col_idx + 1, "end of line"));
+                }
+                let value = cell(c).ok_or_else(|| line.error(col_idx + 1, expected))?;
+                cells.push(value);
+            }
+            if cells.len() - row_start < width {
+                return Err(line.error_at_end(format!("{width} cells in each row")));
+            }
+        }
+        Ok(DenseGrid::from_cells(width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: Pos) -> bool {
+        self.cell_idx(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cell_idx(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cell_idx(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// All positions in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    /// All cells in the grid and their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, which satisfies `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Cells in row `y`, from left to right.
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    /// Cells in column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    /// Cells from the one after `start` in `direction`, repeatedly stepping in `direction` until
+    /// the edge of the grid.
+    pub fn ray(&self, start: Pos, direction: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        let mut pos = start;
+        std::iter::from_fn(move || {
+            pos += direction;
+            self.get(pos).map(|cell| (pos, cell))
+        })
+    }
+
+    /// Orthogonally adjacent cells which are within the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.neighbours4()
+            .into_iter()
+            .filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// Adjacent cells, including diagonals, which are within the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.neighbours8()
+            .into_iter()
+            .filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// Grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> DenseGrid<U> {
+        DenseGrid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Renders the grid as text, one line per row, using `cell` to convert each cell to a
+    /// character.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        (0..self.height)
+            .map(|y| self.row(y).map(&cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn cell_idx(&self, pos: Pos) -> Option<usize> {
+        let x = usize::try_from(pos.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(pos.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+}
+
+/// Panics if `pos` is out of bounds.
+impl<T> Index<Pos> for DenseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is out of bounds"))
+    }
+}
+
+/// Panics if `pos` is out of bounds.
+impl<T> IndexMut<Pos> for DenseGrid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is out of bounds"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseError;
+
+    use super::DenseGrid;
+    use crate::Pos;
+
+    fn digits(input: &str) -> Result<DenseGrid<u32>, ParseError> {
+        DenseGrid::parse(input, |c| c.to_digit(10), "a digit")
+    }
+
+    #[test]
+    fn parse_and_render() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Pos::new(2, 1)], 6);
+        assert_eq!(
+            grid.render(|&d| char::from_digit(d, 10).unwrap()),
+            "123\n456"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(digits("123\n4x6\n"), Err(ParseError::new(2, 2, "a digit")));
+        assert_eq!(
+            digits("123\n45\n"),
+            Err(ParseError::new(2, 3, "3 cells in each row"))
+        );
+        assert_eq!(
+            digits("123\n4567\n"),
+            Err(ParseError::new(2, 4, "end of line"))
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let mut grid = DenseGrid::new(2, 3, 0);
+        assert!(grid.in_bounds(Pos::new(1, 2)));
+        assert!(!grid.in_bounds(Pos::new(2, 0)));
+        assert!(!grid.in_bounds(Pos::new(0, -1)));
+        assert_eq!(grid.get(Pos::new(0, 3)), None);
+        *grid.get_mut(Pos::new(1, 1)).unwrap() = 5;
+        grid[Pos::new(0, 2)] = 7;
+        assert_eq!(
+            grid.render(|&d| char::from_digit(d, 10).unwrap()),
+            "00\n05\n70"
+        );
+    }
+
+    #[test]
+    fn iterators() {
+        let grid = digits("123\n456\n789\n").unwrap();
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [4, 5, 6]);
+        assert_eq!(grid.column(2).rev().copied().collect::<Vec<_>>(), [9, 6, 3]);
+        assert_eq!(
+            grid.ray(Pos::new(0, 0), Pos::DOWN_RIGHT)
+                .collect::<Vec<_>>(),
+            [(Pos::new(1, 1), &5), (Pos::new(2, 2), &9)]
+        );
+        assert_eq!(grid.position(|&d| d == 8), Some(Pos::new(1, 2)));
+        assert_eq!(grid.iter().nth(4), Some((Pos::new(1, 1), &5)));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits("123\n456\n789\n").unwrap();
+        let neighbours4: Vec<u32> = grid.neighbours4(Pos::new(0, 0)).map(|(_, &d)| d).collect();
+        assert_eq!(neighbours4, [2, 4]);
+        let neighbours8: Vec<u32> = grid.neighbours8(Pos::new(1, 1)).map(|(_, &d)| d).collect();
+        assert_eq!(neighbours8, [2, 3, 6, 9, 8, 7, 4, 1]);
+    }
+}
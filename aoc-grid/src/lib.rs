@@ -0,0 +1,16 @@
+//! 2D grids shared by the days whose puzzles take place on a grid.
+//!
+//! Positions are [`Pos`]es, which use the same axes as [`aoc_common::geometry`]: `y` increases
+//! downwards, matching the way grids are laid out in puzzle input. [`DenseGrid`] stores a value
+//! for every position in a `width * height` rectangle with its top left corner at the origin,
+//! while [`SparseGrid`] only stores the positions which have been set and grows its bounds as
+//! they are added.
+
+mod dense;
+mod sparse;
+
+pub use dense::DenseGrid;
+pub use sparse::SparseGrid;
+
+/// Position on a grid, or the offset between two positions.
+pub type Pos = aoc_common::Vec2<i32>;
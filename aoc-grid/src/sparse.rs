@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use aoc_common::{error, ParseError};
+
+use crate::Pos;
+
+/// Grid which only stores the positions which have been set. Its bounds are the smallest
+/// rectangle containing every position which has been set or explicitly included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    bounds: Option<(Pos, Pos)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    /// Parses a grid with one row per line and one cell per character, with the first character
+    /// at the origin. Characters equal to `empty` are left unset. `cell` converts every other
+    /// character to a cell, returning `None` if the character isn't valid, in which case an error
+    /// saying that `expected` was expected is reported at that character.
+    pub fn parse(
+        input: &str,
+        empty: char,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<SparseGrid<T>, ParseError> {
+        let mut grid = SparseGrid::new();
+        for line in error::lines(input) {
+            for (col_idx, c) in line.text.chars().enumerate() {
+                let pos = Pos::new(col_idx as i32, line.number as i32 - 1);
+                grid.include(pos);
+                if c != empty {
+                    let value = cell(c).ok_or_else(|| line.error(col_idx + 1, expected))?;
+                    grid.insert(pos, value);
+                }
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Inclusive `(min, max)` corners of the grid's bounds, or `None` if nothing has been set or
+    /// included.
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        self.bounds
+    }
+
+    pub fn in_bounds(&self, pos: Pos) -> bool {
+        self.bounds.is_some_and(|(min, max)| {
+            (min.x..=max.x).contains(&pos.x) && (min.y..=max.y).contains(&pos.y)
+        })
+    }
+
+    /// Grows the bounds of the grid to include `pos`, without setting it.
+    pub fn include(&mut self, pos: Pos) {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min(pos), max.max(pos)),
+            None => (pos, pos),
+        });
+    }
+
+    /// Sets `pos` to `value`, growing the bounds to include it. Returns the previous value.
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.include(pos);
+        self.cells.insert(pos, value)
+    }
+
+    /// Unsets `pos`, returning its value. The bounds are left unchanged.
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Number of positions which are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Positions which are set and their cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+
+    /// Positions from the one after `start` in `direction`, repeatedly stepping in `direction`
+    /// until leaving the bounds of the grid, with the cell at each position if it is set.
+    pub fn ray(&self, start: Pos, direction: Pos) -> impl Iterator<Item = (Pos, Option<&T>)> {
+        let mut pos = start;
+        std::iter::from_fn(move || {
+            pos += direction;
+            self.in_bounds(pos).then(|| (pos, self.get(pos)))
+        })
+    }
+
+    /// Orthogonally adjacent positions which are set.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.neighbours4()
+            .into_iter()
+            .filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// Adjacent positions, including diagonals, which are set.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.neighbours8()
+            .into_iter()
+            .filter_map(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// Renders the bounds of the grid as text, one line per row. Unset positions are rendered as
+    /// `empty`, and `cell` converts every set cell to a character.
+    pub fn render(&self, empty: char, cell: impl Fn(&T) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| self.get(Pos::new(x, y)).map_or(empty, &cell))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseError;
+
+    use super::SparseGrid;
+    use crate::Pos;
+
+    #[test]
+    fn insert_grows_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Pos::new(2, -1), '#');
+        grid.include(Pos::new(0, 0));
+        assert_eq!(grid.bounds(), Some((Pos::new(0, -1), Pos::new(2, 0))));
+        assert!(grid.contains(Pos::new(2, -1)));
+        assert!(!grid.contains(Pos::new(0, 0)));
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.render('.', |&c| c), "..#\n...");
+    }
+
+    #[test]
+    fn parse_and_render() {
+        let input = "#..\n.o#\n";
+        let grid = SparseGrid::parse(input, '.', |c| "#o".contains(c).then_some(c), "'#' or 'o'");
+        let grid = grid.unwrap();
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&'o'));
+        assert_eq!(grid.render('.', |&c| c), "#..\n.o#");
+        assert_eq!(
+            SparseGrid::parse("#x\n", '.', |c| (c == '#').then_some(c), "'#'"),
+            Err(ParseError::new(1, 2, "'#'"))
+        );
+    }
+
+    #[test]
+    fn ray_and_neighbours() {
+        let grid = SparseGrid::parse("#..\n.o#\n", '.', Some, "any character").unwrap();
+        assert_eq!(
+            grid.ray(Pos::new(0, 1), Pos::RIGHT).collect::<Vec<_>>(),
+            [(Pos::new(1, 1), Some(&'o')), (Pos::new(2, 1), Some(&'#'))]
+        );
+        assert_eq!(
+            grid.ray(Pos::new(0, 0), Pos::RIGHT).collect::<Vec<_>>(),
+            [(Pos::new(1, 0), None), (Pos::new(2, 0), None)]
+        );
+        let mut neighbours: Vec<Pos> = grid
+            .neighbours8(Pos::new(1, 0))
+            .map(|(pos, _)| pos)
+            .collect();
+        neighbours.sort();
+        assert_eq!(neighbours, [Pos::new(0, 0), Pos::new(1, 1), Pos::new(2, 1)]);
+        assert_eq!(grid.neighbours4(Pos::new(1, 0)).count(), 2);
+    }
+}
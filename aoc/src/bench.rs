@@ -0,0 +1,281 @@
+//! Timing of the parse, part 1 and part 2 phases of each day's solution, reported as a table or
+//! as JSON which can be saved and used as a baseline for later runs.
+
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{ParseError, Solution};
+use serde::{Deserialize, Serialize};
+
+/// Times each phase of a day's solution on the given input, over the given number of runs.
+pub type Timer = fn(&str, u32) -> Result<Timings, ParseError>;
+
+/// Median time taken by each phase of a day's solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timings {
+    #[serde(rename = "parse_ns", with = "nanos")]
+    pub parse: Duration,
+    #[serde(rename = "part_one_ns", with = "nanos")]
+    pub part_one: Duration,
+    #[serde(rename = "part_two_ns", with = "nanos")]
+    pub part_two: Duration,
+}
+
+impl Timings {
+    /// The phases' timings in order, with their names.
+    fn phases(&self) -> [(&'static str, Duration); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part_one),
+            ("part 2", self.part_two),
+        ]
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+/// Timings of a single day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub day: u8,
+    #[serde(flatten)]
+    pub timings: Timings,
+}
+
+/// Timings of every benchmarked day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    /// Number of times each phase was run. Each timing is the median of these runs.
+    pub runs: u32,
+    pub days: Vec<DayTimings>,
+}
+
+/// A phase which was slower than in the baseline by more than the allowed threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: &'static str,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// Relative change from the baseline, as a percentage.
+    pub fn change(&self) -> f64 {
+        percent_change(self.baseline, self.current)
+    }
+}
+
+/// Parses `input` and solves both parts `runs` times, timing each phase separately.
+pub fn time<S: Solution>(input: &str, runs: u32) -> Result<Timings, ParseError> {
+    assert!(runs > 0, "at least one run is needed");
+    let mut parse = Vec::new();
+    let mut part_one = Vec::new();
+    let mut part_two = Vec::new();
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input))?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part_one(black_box(&parsed)));
+        part_one.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part_two(black_box(&parsed)));
+        part_two.push(start.elapsed());
+    }
+    Ok(Timings {
+        parse: median(parse),
+        part_one: median(part_one),
+        part_two: median(part_two),
+    })
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a report can always be serialized")
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Report> {
+        serde_json::from_str(json)
+    }
+
+    fn day(&self, day: u8) -> Option<&Timings> {
+        self.days
+            .iter()
+            .find(|timings| timings.day == day)
+            .map(|timings| &timings.timings)
+    }
+
+    /// Renders the timings as a table with one row per day. If a `baseline` is given, each
+    /// timing is followed by its change relative to the same day's timing in the baseline.
+    pub fn table(&self, baseline: Option<&Report>) -> String {
+        let mut table = format!("{:>3}", "day");
+        for heading in ["parse", "part 1", "part 2", "total"] {
+            table += &cell(heading, None);
+        }
+        table += "\n";
+        for DayTimings { day, timings } in &self.days {
+            let baseline = baseline.and_then(|baseline| baseline.day(*day));
+            write!(table, "{day:>3}").unwrap();
+            for (idx, (_, duration)) in timings.phases().into_iter().enumerate() {
+                let change = baseline.map(|baseline| (baseline.phases()[idx].1, duration));
+                table += &cell(&format_duration(duration), change);
+            }
+            let change = baseline.map(|baseline| (baseline.total(), timings.total()));
+            table += &cell(&format_duration(timings.total()), change);
+            table += "\n";
+        }
+        table
+    }
+
+    /// Phases which are more than `threshold` percent slower than in the baseline. Days missing
+    /// from either report are skipped.
+    pub fn regressions(&self, baseline: &Report, threshold: f64) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+        for DayTimings { day, timings } in &self.days {
+            let Some(baseline) = baseline.day(*day) else {
+                continue;
+            };
+            for ((phase, current), (_, baseline)) in
+                timings.phases().into_iter().zip(baseline.phases())
+            {
+                if percent_change(baseline, current) > threshold {
+                    regressions.push(Regression {
+                        day: *day,
+                        phase,
+                        baseline,
+                        current,
+                    });
+                }
+            }
+        }
+        regressions
+    }
+}
+
+/// A right-aligned table cell, with room for a relative change from `(baseline, current)`.
+fn cell(text: &str, change: Option<(Duration, Duration)>) -> String {
+    match change {
+        Some((baseline, current)) => {
+            let change = format!("{:+.1}%", percent_change(baseline, current));
+            format!("{text:>11} {change:>7}")
+        }
+        None => format!("{text:>11}"),
+    }
+}
+
+fn percent_change(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_secs_f64();
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (current.as_secs_f64() - baseline) / baseline * 100.0
+}
+
+/// Formats a duration with a unit which suits its magnitude, e.g. `153.2µs`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{nanos}ns")
+    } else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+/// Serializes durations as a whole number of nanoseconds.
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_duration, DayTimings, Report, Timings};
+
+    fn report(timings: &[(u8, [u64; 3])]) -> Report {
+        let days = timings
+            .iter()
+            .map(|&(day, [parse, part_one, part_two])| DayTimings {
+                day,
+                timings: Timings {
+                    parse: Duration::from_micros(parse),
+                    part_one: Duration::from_micros(part_one),
+                    part_two: Duration::from_micros(part_two),
+                },
+            })
+            .collect();
+        Report { runs: 5, days }
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let report = report(&[(1, [10, 20, 30]), (15, [5, 500_000, 2_000_000])]);
+        let json = report.to_json();
+        assert!(json.contains("\"part_one_ns\": 20000"));
+        assert_eq!(Report::from_json(&json).unwrap(), report);
+        assert!(Report::from_json("{\"runs\": 1}").is_err());
+    }
+
+    #[test]
+    fn table() {
+        let current = report(&[(1, [10, 20, 30]), (2, [1, 1, 1])]);
+        let baseline = report(&[(1, [10, 10, 40])]);
+        let table = current.table(None);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "day      parse     part 1     part 2      total");
+        assert_eq!(lines[1], "  1     10.0µs     20.0µs     30.0µs     60.0µs");
+        let table = current.table(Some(&baseline));
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[1],
+            "  1     10.0µs   +0.0%     20.0µs +100.0%     30.0µs  -25.0%     60.0µs   +0.0%"
+        );
+        assert_eq!(lines[2], "  2      1.0µs      1.0µs      1.0µs      3.0µs");
+    }
+
+    #[test]
+    fn regressions() {
+        let current = report(&[(1, [10, 20, 30]), (2, [1, 1, 1])]);
+        let baseline = report(&[(1, [10, 10, 40]), (3, [1, 1, 1])]);
+        let regressions = current.regressions(&baseline, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!((regressions[0].day, regressions[0].phase), (1, "part 1"));
+        assert_eq!(regressions[0].change(), 100.0);
+        assert!(current.regressions(&baseline, 150.0).is_empty());
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(153_250)), "153.2µs");
+        assert_eq!(format_duration(Duration::from_millis(12)), "12.0ms");
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50s");
+    }
+}
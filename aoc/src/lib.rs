@@ -0,0 +1,78 @@
+//! Registry of every day's solution and the inputs checked in alongside it, shared by the runner
+//! and the answer regression tests.
+
+use std::path::{Path, PathBuf};
+
+use aoc_common::{input::InputSource, solution::solve, ParseError, Solution};
+
+pub mod bench;
+
+/// Parses the input and solves the given part (1 or 2) of a day's puzzle.
+pub type Solver = fn(&str, u8) -> Result<Option<String>, ParseError>;
+
+/// Entry points into a day's [`Solution`] which don't depend on its types.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub solve: Solver,
+    pub time: bench::Timer,
+}
+
+impl Day {
+    fn of<S: Solution>() -> Day {
+        Day {
+            solve: solve::<S>,
+            time: bench::time::<S>,
+        }
+    }
+}
+
+/// Days which have a solution.
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=15;
+
+/// Directory containing `day`'s solution.
+fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day-{day:02}"))
+}
+
+/// Puzzle input checked into the repository for `day`.
+pub fn default_input(day: u8) -> InputSource {
+    let path = match day {
+        15 => day_dir(day).join("test_cases").join("aoc_actual.txt"),
+        _ => day_dir(day).join("input.txt"),
+    };
+    InputSource::File(path)
+}
+
+/// Example input from `day`'s puzzle description, checked into the repository.
+pub fn example_input(day: u8) -> InputSource {
+    let path = match day {
+        14 => day_dir(day).join("input_test.txt"),
+        15 => day_dir(day).join("test_cases").join("aoc_example.txt"),
+        _ => day_dir(day).join("test_input.txt"),
+    };
+    InputSource::File(path)
+}
+
+/// Returns the solution to `day`'s puzzle, if there is one.
+pub fn day(day: u8) -> Option<Day> {
+    Some(match day {
+        1 => Day::of::<day_01::Day01>(),
+        2 => Day::of::<day_02::Day02>(),
+        3 => Day::of::<day_03::Day03>(),
+        4 => Day::of::<day_04::Day04>(),
+        5 => Day::of::<day_05::Day05>(),
+        6 => Day::of::<day_06::Day06>(),
+        7 => Day::of::<day_07::Day07>(),
+        8 => Day::of::<day_08::Day08>(),
+        9 => Day::of::<day_09::Day09>(),
+        10 => Day::of::<day_10::Day10>(),
+        11 => Day::of::<day_11::Day11>(),
+        12 => Day::of::<day_12::Day12>(),
+        13 => Day::of::<day_13::Day13>(),
+        14 => Day::of::<day_14::Day14>(),
+        15 => Day::of::<day_15::Day15>(),
+        _ => return None,
+    })
+}
@@ -0,0 +1,219 @@
+//! Runs the solution to any day's puzzle from a single place, or times every day's solution.
+//!
+//! ```shell
+//! $ cargo run --release -p aoc -- run 12 2
+//! $ cargo run --release -p aoc -- run 12 2 --input day-12/test_input.txt
+//! $ cat day-12/test_input.txt | cargo run --release -p aoc -- run 12 2 --input -
+//! $ cargo run --release -p aoc -- bench --json baseline.json
+//! $ cargo run --release -p aoc -- bench 13 14 --baseline baseline.json
+//! ```
+//!
+//! `bench` exits with a nonzero status if any phase regressed beyond the threshold relative to the
+//! baseline, so it can gate a CI job.
+
+use std::{env, fs, process};
+
+use aoc::{
+    bench::{format_duration, DayTimings, Report},
+    default_input, DAYS,
+};
+use aoc_common::input::InputSource;
+
+const USAGE: &str = "\
+Usage: aoc run <day> <part> [--input PATH | --input -]
+       aoc bench [<day>...] [--runs N] [--json PATH | --json -] [--baseline PATH] [--threshold PERCENT]";
+
+/// Number of times each phase is run by `bench` unless `--runs` is given.
+const DEFAULT_RUNS: u32 = 10;
+/// Slowdown relative to the baseline, as a percentage, above which `bench` reports a regression
+/// unless `--threshold` is given.
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug)]
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+}
+
+#[derive(Debug)]
+struct RunArgs {
+    day: u8,
+    part: u8,
+    input: Option<InputSource>,
+}
+
+#[derive(Debug)]
+struct BenchArgs {
+    days: Vec<u8>,
+    runs: u32,
+    /// Where to write the report as JSON, or `-` to print it instead of the table.
+    json: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match parse_args(&args) {
+        Ok(Command::Run(run_args)) => run(run_args),
+        Ok(Command::Bench(bench_args)) => bench(bench_args),
+        Err(message) => {
+            eprintln!("error: {message}");
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    }
+}
+
+fn run(run_args: RunArgs) {
+    let input_source = run_args
+        .input
+        .unwrap_or_else(|| default_input(run_args.day));
+    let input = read(&input_source);
+
+    let answer = match aoc::day(run_args.day).map(|day| (day.solve)(&input, run_args.part)) {
+        Some(Ok(answer)) => answer,
+        Some(Err(err)) => exit_with_error(&format!("{input_source}:{err}")),
+        None => None,
+    };
+    match answer {
+        Some(answer) => println!("{answer}"),
+        None => exit_with_error(&format!(
+            "no solution for day {} part {}",
+            run_args.day, run_args.part
+        )),
+    }
+}
+
+fn bench(bench_args: BenchArgs) {
+    let baseline = bench_args.baseline.as_ref().map(|path| {
+        let json = read(&InputSource::from(path.as_str()));
+        Report::from_json(&json).unwrap_or_else(|err| exit_with_error(&format!("{path}: {err}")))
+    });
+
+    let mut report = Report {
+        runs: bench_args.runs,
+        days: Vec::new(),
+    };
+    for day in bench_args.days {
+        let time = aoc::day(day)
+            .unwrap_or_else(|| exit_with_error(&format!("no solution for day {day}")))
+            .time;
+        let input_source = default_input(day);
+        let input = read(&input_source);
+        let timings = time(&input, bench_args.runs)
+            .unwrap_or_else(|err| exit_with_error(&format!("{input_source}:{err}")));
+        report.days.push(DayTimings { day, timings });
+    }
+
+    match bench_args.json.as_deref() {
+        Some("-") => println!("{}", report.to_json()),
+        Some(path) => {
+            print!("{}", report.table(baseline.as_ref()));
+            fs::write(path, report.to_json() + "\n")
+                .unwrap_or_else(|err| exit_with_error(&format!("failed to write {path}: {err}")));
+        }
+        None => print!("{}", report.table(baseline.as_ref())),
+    }
+
+    if let Some(baseline) = &baseline {
+        let regressions = report.regressions(baseline, bench_args.threshold);
+        for regression in &regressions {
+            eprintln!(
+                "regression: day {} {} took {}, up from {} ({:+.1}%)",
+                regression.day,
+                regression.phase,
+                format_duration(regression.current),
+                format_duration(regression.baseline),
+                regression.change()
+            );
+        }
+        if !regressions.is_empty() {
+            process::exit(1);
+        }
+    }
+}
+
+fn read(source: &InputSource) -> String {
+    source
+        .read()
+        .unwrap_or_else(|err| exit_with_error(&err.to_string()))
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("error: {message}");
+    process::exit(1);
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    match args.next().map(String::as_str) {
+        Some("run") => parse_run_args(args).map(Command::Run),
+        Some("bench") => parse_bench_args(args).map(Command::Bench),
+        Some(command) => Err(format!("unknown command '{command}'")),
+        None => Err("missing command".to_string()),
+    }
+}
+
+fn parse_run_args<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<RunArgs, String> {
+    let mut positional = Vec::new();
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        if arg == "--input" {
+            let path = args
+                .next()
+                .ok_or("--input requires a path, or - for stdin")?;
+            input = Some(InputSource::from(path.as_str()));
+        } else {
+            positional.push(arg);
+        }
+    }
+
+    let [day, part] = positional[..] else {
+        return Err("expected <day> and <part>".to_string());
+    };
+    let day = day.parse().map_err(|_| format!("invalid day '{day}'"))?;
+    let part = part.parse().map_err(|_| format!("invalid part '{part}'"))?;
+    Ok(RunArgs { day, part, input })
+}
+
+fn parse_bench_args<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        days: Vec::new(),
+        runs: DEFAULT_RUNS,
+        json: None,
+        baseline: None,
+        threshold: DEFAULT_THRESHOLD,
+    };
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{arg} requires a value"));
+        match arg.as_str() {
+            "--runs" => {
+                let runs = value()?;
+                bench_args.runs = runs
+                    .parse()
+                    .ok()
+                    .filter(|&runs| runs > 0)
+                    .ok_or(format!("invalid number of runs '{runs}'"))?;
+            }
+            "--json" => bench_args.json = Some(value()?.clone()),
+            "--baseline" => bench_args.baseline = Some(value()?.clone()),
+            "--threshold" => {
+                let threshold = value()?;
+                // Also rejects NaN, which compares false with everything
+                bench_args.threshold = threshold
+                    .parse()
+                    .ok()
+                    .filter(|&threshold: &f64| threshold >= 0.0)
+                    .ok_or(format!("invalid threshold '{threshold}'"))?;
+            }
+            day => bench_args
+                .days
+                .push(day.parse().map_err(|_| format!("invalid day '{day}'"))?),
+        }
+    }
+    if bench_args.days.is_empty() {
+        bench_args.days = DAYS.collect();
+    }
+    Ok(bench_args)
+}
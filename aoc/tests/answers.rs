@@ -0,0 +1,129 @@
+//! Answer regression tests: every day's solution is run on the example from the puzzle
+//! description and on the actual puzzle input, and checked against the recorded answers.
+
+use aoc_common::input::InputSource;
+
+/// Solves both parts of `day` for the example input and the actual input, checking them against
+/// the expected `[part 1, part 2]` answers.
+fn check(day: u8, example: [&str; 2], actual: [&str; 2]) {
+    let solve = aoc::day(day)
+        .unwrap_or_else(|| panic!("no solution for day {day}"))
+        .solve;
+    for (source, expected) in [
+        (aoc::example_input(day), example),
+        (aoc::default_input(day), actual),
+    ] {
+        let input = read(&source);
+        for (part, expected) in (1..=2).zip(expected) {
+            let answer = solve(&input, part).unwrap_or_else(|err| panic!("{source}:{err}"));
+            assert_eq!(
+                answer.as_deref(),
+                Some(expected),
+                "day {day} part {part} on {source}"
+            );
+        }
+    }
+}
+
+fn read(source: &InputSource) -> String {
+    source.read().unwrap_or_else(|err| panic!("{err}"))
+}
+
+#[test]
+fn every_day_is_checked() {
+    for day in aoc::DAYS {
+        assert!(aoc::day(day).is_some(), "no solution for day {day}");
+        read(&aoc::example_input(day));
+        read(&aoc::default_input(day));
+    }
+}
+
+#[test]
+fn day_01() {
+    check(1, ["24000", "45000"], ["68787", "198041"]);
+}
+
+#[test]
+fn day_02() {
+    check(2, ["15", "12"], ["12740", "11980"]);
+}
+
+#[test]
+fn day_03() {
+    check(3, ["157", "70"], ["8176", "2689"]);
+}
+
+#[test]
+fn day_04() {
+    check(4, ["2", "4"], ["471", "888"]);
+}
+
+#[test]
+fn day_05() {
+    check(5, ["CMZ", "MCD"], ["TBVFVDZPN", "VLCWHTDSZ"]);
+}
+
+#[test]
+fn day_06() {
+    check(6, ["10", "29"], ["1034", "2472"]);
+}
+
+#[test]
+fn day_07() {
+    check(7, ["95437", "24933642"], ["1206825", "9608311"]);
+}
+
+#[test]
+fn day_08() {
+    check(8, ["21", "8"], ["1543", "595080"]);
+}
+
+#[test]
+fn day_09() {
+    check(9, ["13", "1"], ["5902", "2445"]);
+}
+
+#[test]
+fn day_10() {
+    const EXAMPLE_CRT: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+    // Spells "EZFPRAKL".
+    const ACTUAL_CRT: &str = "\
+####.####.####.###..###...##..#..#.#....
+#.......#.#....#..#.#..#.#..#.#.#..#....
+###....#..###..#..#.#..#.#..#.##...#....
+#.....#...#....###..###..####.#.#..#....
+#....#....#....#....#.#..#..#.#.#..#....
+####.####.#....#....#..#.#..#.#..#.####.";
+    check(10, ["13140", EXAMPLE_CRT], ["12460", ACTUAL_CRT]);
+}
+
+#[test]
+fn day_11() {
+    check(11, ["10605", "2713310158"], ["113220", "30599555965"]);
+}
+
+#[test]
+fn day_12() {
+    check(12, ["31", "29"], ["484", "478"]);
+}
+
+#[test]
+fn day_13() {
+    check(13, ["13", "140"], ["5390", "19261"]);
+}
+
+#[test]
+fn day_14() {
+    check(14, ["24", "93"], ["614", "26170"]);
+}
+
+#[test]
+fn day_15() {
+    check(15, ["26", "56000011"], ["5073496", "13081194638237"]);
+}
@@ -0,0 +1,36 @@
+use aoc_common::{error, ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// Total calories carried by each elf.
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        // Iterate lines and sum the calories carried by each elf
+        let mut sums = vec![0];
+        for line in error::lines(input) {
+            let curr = sums.len() - 1;
+            if line.text.is_empty() {
+                sums.push(0);
+            } else {
+                sums[curr] += line.parse::<u64>(line.text, "a calorie count or a blank line")?;
+            }
+        }
+        Ok(sums)
+    }
+
+    /// Total calories carried by the elf carrying the most calories.
+    fn part_one(sums: &Vec<u64>) -> u64 {
+        sums.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Total calories carried by the top 3 elves.
+    fn part_two(sums: &Vec<u64>) -> u64 {
+        let mut sums = sums.clone();
+        sums.sort();
+        sums.into_iter().rev().take(3).sum()
+    }
+}
@@ -0,0 +1,110 @@
+/*
+let A = X = ROCK     = 0
+let B = Y = PAPER    = 1
+let C = Z = SCISSORS = 2
+let DRAW = 0
+let WIN  = 1
+let LOSE = 2
+
+part 1:
+    All possible outcomes:
+    A A = 0-0 = +0 = DRAW
+    A B = 1-0 = +1 = WIN
+    A C = 2-0 = +2 = LOSE
+    B A = 0-1 = -1 = LOSE
+    B B = 1-1 = +0 = DRAW
+    B C = 2-1 = +1 = WIN
+    C A = 0-2 = -2 = WIN
+    C B = 1-2 = -1 = LOSE
+    C C = 2-2 = +0 = DRAW
+
+Mapping WIN/DRAW/LOSE to {-2, -1, 0, 1, 2}
+    -2 rem 3 = 1 = WIN
+    -1 rem 3 = 2 = LOSE
+    +0 rem 3 = 0 = DRAW
+    +1 rem 3 = 1 = WIN
+    +2 rem 3 = 2 = LOSE
+
+part 2:
+    A X = ROCK(0), play SCISSORS(2) to LOSE(0) = 0 + LOSE - 1
+    A Y = ROCK(0), play ROCK    (0) to DRAW(1) = 0 + DRAW - 1
+    A Z = ROCK(0), play PAPER   (1) to WIN (2) = 0 + WIN  - 1
+etc...
+
+*/
+
+use aoc_common::{error, ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    /// Normalised `(opponent_choice, second_column)` for each round.
+    type Input = Vec<(i32, i32)>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+        let mut rounds = Vec::new();
+        for line in error::lines(input) {
+            let mut char_iter = line.text.chars();
+
+            // # Read choices
+            // Read char representing the opponents choice
+            let opponent_choice = match char_iter.next() {
+                Some(c @ 'A'..='C') => c,
+                _ => return Err(line.error(1, "'A', 'B' or 'C'")),
+            };
+            // Read whitespace char which only serves as a separator
+            if char_iter.next() != Some(' ') {
+                return Err(line.error(2, "' '"));
+            }
+            // Read char representing "my" choice in part 1, or the outcome in part 2
+            let second_column = match char_iter.next() {
+                Some(c @ 'X'..='Z') => c,
+                _ => return Err(line.error(3, "'X', 'Y' or 'Z'")),
+            };
+            if char_iter.next().is_some() {
+                return Err(line.error(4, "end of line"));
+            }
+
+            // # Normalise representation of choices
+            let opponent_choice = (u32::from(opponent_choice) - u32::from('A')) as i32;
+            let second_column = (u32::from(second_column) - u32::from('X')) as i32;
+            rounds.push((opponent_choice, second_column));
+        }
+        Ok(rounds)
+    }
+
+    /// Total score when the second column is the shape to play.
+    fn part_one(rounds: &Vec<(i32, i32)>) -> i32 {
+        rounds
+            .iter()
+            .map(|&(opponent_choice, my_choice)| round_score(opponent_choice, my_choice))
+            .sum()
+    }
+
+    /// Total score when the second column is the desired outcome.
+    fn part_two(rounds: &Vec<(i32, i32)>) -> i32 {
+        let mut score = 0;
+        for &(opponent_choice, outcome) in rounds {
+            // # Calculate "my choice"
+            let my_choice = (opponent_choice + outcome - 1).rem_euclid(3);
+
+            // # Calculate score
+            score += round_score(opponent_choice, my_choice);
+        }
+        score
+    }
+}
+
+fn round_score(opponent_choice: i32, my_choice: i32) -> i32 {
+    let diff = my_choice - opponent_choice;
+    let outcome = diff.rem_euclid(3);
+    let outcome_score = match outcome {
+        0 => 3,
+        1 => 6,
+        2 => 0,
+        _ => unreachable!(),
+    };
+    outcome_score + my_choice + 1
+}
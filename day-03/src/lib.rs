@@ -0,0 +1,92 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{error, ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    /// Contents of each rucksack.
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        let mut rucksacks = Vec::new();
+        for line in error::lines(input) {
+            if let Some(column) = line.text.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(line.error(column + 1, "an item type (a-z or A-Z)"));
+            }
+            if line.text.len() % 2 != 0 {
+                return Err(line.error_at_end("the same number of items in each compartment"));
+            }
+            rucksacks.push(line.text.to_string());
+        }
+        if rucksacks.len() % 3 != 0 {
+            return Err(ParseError::end_of_input(
+                input,
+                "a rucksack for each elf in the group",
+            ));
+        }
+        Ok(rucksacks)
+    }
+
+    /// Sum of the priorities of the item shared by both compartments of each rucksack.
+    fn part_one(rucksacks: &Vec<String>) -> u32 {
+        /*
+         * - Iterate each line
+         *   - Get number of chars in each line
+         *   - Iterate characters
+         *      - convert into priority
+         *      - If from first half, read into set
+         *      - Otherwise check if duplicate from set
+         */
+        let mut priority_sum = 0;
+        'lines: for line in rucksacks {
+            // WARNING: This is only correct if the string doesn't contain any multibyte characters.
+            let char_count = line.len();
+            let mut compartment_1_priorities = HashSet::new();
+            for (current_char, c) in line.chars().enumerate() {
+                assert!(c.is_ascii(), "Non ASCII input.");
+                let priority = char_to_priority(c);
+                if current_char < char_count / 2 {
+                    compartment_1_priorities.insert(priority);
+                } else if compartment_1_priorities.contains(&priority) {
+                    priority_sum += priority;
+                    continue 'lines;
+                }
+            }
+        }
+        priority_sum
+    }
+
+    /// Sum of the priorities of the badge item shared by each group of 3 elves.
+    fn part_two(rucksacks: &Vec<String>) -> u32 {
+        let mut priority_sum = 0;
+        for group in rucksacks.chunks(3) {
+            let mut char_counts = HashMap::new();
+            for line in group {
+                let mut line_chars = HashSet::new();
+                for c in line.chars() {
+                    if !line_chars.contains(&c) {
+                        char_counts.entry(c).and_modify(|c| *c += 1).or_insert(1);
+                    }
+                    line_chars.insert(c);
+                }
+            }
+            let mut common_chars = char_counts.iter().filter(|(_k, v)| **v == 3);
+            let (common_char, _) = common_chars.next().unwrap();
+            assert!(common_chars.count() == 0);
+            priority_sum += char_to_priority(*common_char)
+        }
+        priority_sum
+    }
+}
+
+fn char_to_priority(c: char) -> u32 {
+    let p = u32::from(c);
+    if p >= u32::from('a') {
+        p - u32::from('a') + 1
+    } else {
+        p - u32::from('A') + 27
+    }
+}
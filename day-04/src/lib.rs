@@ -0,0 +1,57 @@
+use std::ops::Range;
+
+use aoc_common::{
+    interval::RangeOps,
+    parse::{self, int, label, literal, Cursor},
+    ParseError, Solution,
+};
+
+/// Pair of section assignment ranges.
+type Pair = (Range<i32>, Range<i32>);
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Pair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+        parse::parse_lines(input, parse_pair)
+    }
+
+    /// Number of pairs where one range fully contains the other.
+    fn part_one(pairs: &Vec<Pair>) -> usize {
+        pairs
+            .iter()
+            .filter(|(range_1, range_2)| {
+                range_1.contains_range(range_2) || range_2.contains_range(range_1)
+            })
+            .count()
+    }
+
+    /// Number of pairs where the ranges overlap.
+    fn part_two(pairs: &Vec<Pair>) -> usize {
+        pairs
+            .iter()
+            .filter(|(range_1, range_2)| range_1.overlaps(range_2))
+            .count()
+    }
+}
+
+/// Parses a pair of the form "2-4,6-8".
+fn parse_pair(cursor: &mut Cursor) -> Result<Pair, ParseError> {
+    let range_1 = parse_range(cursor)?;
+    literal(",")(cursor)?;
+    let range_2 = parse_range(cursor)?;
+    Ok((range_1, range_2))
+}
+
+/// Parses an inclusive range of section IDs of the form "2-4", as the half-open range `2..5`.
+fn parse_range(cursor: &mut Cursor) -> Result<Range<i32>, ParseError> {
+    let section_id = label(int, "a section ID");
+    let lower = section_id(cursor)?;
+    literal("-")(cursor)?;
+    let upper: i32 = section_id(cursor)?;
+    Ok(lower..upper + 1)
+}
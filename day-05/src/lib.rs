@@ -0,0 +1,120 @@
+use aoc_common::{
+    error::{self, Line},
+    parse::{self, int, literal, verify, Cursor},
+    ParseError, Solution,
+};
+
+pub struct Day05;
+
+#[derive(Debug, Clone)]
+pub struct Procedure {
+    stacks: Vec<Vec<char>>,
+    stack_ops: Vec<StackOperation>,
+}
+
+impl Solution for Day05 {
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Procedure, ParseError> {
+        let mut line_iterator = error::lines(input);
+        let stacks = parse_stacks(input, &mut line_iterator)?;
+        let stack_ops = line_iterator
+            .map(|line| parse_stack_op(line, stacks.len()))
+            .collect::<Result<_, _>>()?;
+        Ok(Procedure { stacks, stack_ops })
+    }
+
+    /// Crates on top of each stack after moving crates one at a time.
+    fn part_one(procedure: &Procedure) -> String {
+        let mut stacks = procedure.stacks.clone();
+
+        // # Manipulate stacks
+        for stack_op in &procedure.stack_ops {
+            for _ in 0..stack_op.count {
+                let t = stacks[stack_op.from].pop().unwrap();
+                stacks[stack_op.to].push(t);
+            }
+        }
+        top_crates(&stacks)
+    }
+
+    /// Crates on top of each stack after moving crates several at a time.
+    fn part_two(procedure: &Procedure) -> String {
+        let mut stacks = procedure.stacks.clone();
+
+        // # Manipulate stacks
+        for stack_op in &procedure.stack_ops {
+            let new_len = stacks[stack_op.from].len() - stack_op.count;
+            let stack_to_move: Vec<char> = stacks[stack_op.from].drain(new_len..).collect();
+            stacks[stack_op.to].extend(stack_to_move);
+        }
+        top_crates(&stacks)
+    }
+}
+
+fn top_crates(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|s| s.last()).collect()
+}
+
+/// Parses the initial stack state, and consumes the blank line which separates it from the crane
+/// instructions.
+fn parse_stacks<'a>(
+    input: &str,
+    lines: &mut impl Iterator<Item = Line<'a>>,
+) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut stacks = vec![];
+    'outer: loop {
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "a row of stack numbers"))?;
+        // Iterate create IDs in line. If a digit is encountered, we have reached the end of the crate IDs
+        for (i, c) in line.text.chars().skip(1).step_by(4).enumerate() {
+            if c.is_ascii_digit() {
+                break 'outer;
+            }
+            if c == ' ' {
+                continue;
+            }
+            if !c.is_ascii_uppercase() {
+                return Err(line.error(i * 4 + 2, "a crate ID (A-Z) or ' '"));
+            }
+            while i >= stacks.len() {
+                stacks.push(Vec::new());
+            }
+            stacks[i].push(c);
+        }
+    }
+    for stack in &mut stacks {
+        stack.reverse();
+    }
+    // # Consume blank line which separates initial stack state from crane instructions
+    match lines.next() {
+        Some(line) if !line.text.is_empty() => return Err(line.error(1, "a blank line")),
+        _ => {}
+    }
+    Ok(stacks)
+}
+
+/// Parses a stack operation of the form "move 2 from 8 to 4"
+fn parse_stack_op(line: Line, stack_count: usize) -> Result<StackOperation, ParseError> {
+    let expected_stack = format!("a stack number between 1 and {stack_count}");
+    let stack = verify(int, |&n| (1..=stack_count).contains(&n), &expected_stack);
+    parse::parse_line(line, |cursor: &mut Cursor| {
+        literal("move ")(cursor)?;
+        let count = int(cursor)?;
+        literal(" from ")(cursor)?;
+        let from = stack(cursor)? - 1;
+        literal(" to ")(cursor)?;
+        let to = stack(cursor)? - 1;
+        Ok(StackOperation { count, from, to })
+    })
+}
+
+#[derive(Debug, Clone)]
+struct StackOperation {
+    count: usize,
+    from: usize,
+    to: usize,
+}
@@ -0,0 +1,45 @@
+use aoc_common::{ParseError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    /// Characters of the datastream buffer.
+    type Input = Vec<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<char>, ParseError> {
+        Ok(input.trim_end().chars().collect())
+    }
+
+    /// Number of characters processed before the first start-of-packet marker.
+    fn part_one(chars: &Vec<char>) -> usize {
+        marker_end(chars, 4)
+    }
+
+    /// Number of characters processed before the first start-of-message marker.
+    fn part_two(chars: &Vec<char>) -> usize {
+        marker_end(chars, 14)
+    }
+}
+
+/// Returns the number of characters processed once the last `history_len` characters are all
+/// different.
+fn marker_end(chars: &[char], history_len: usize) -> usize {
+    for (i, history) in chars.windows(history_len).enumerate() {
+        // # Check if end condition met
+        let mut end = true;
+        for a in 0..history_len {
+            for b in 0..history_len {
+                if a != b && history[a] == history[b] {
+                    end = false;
+                }
+            }
+        }
+
+        if end {
+            return i + history_len;
+        }
+    }
+    panic!("No marker found");
+}
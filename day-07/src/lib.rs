@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use aoc_common::{error, ParseError, Solution};
+
+const SIZE_THRESHOLD: u32 = 100000;
+const TOTAL_SPACE: u32 = 70_000_000;
+const REQUIRED_SPACE: u32 = 30_000_000;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    /// Total size of each directory, keyed by path.
+    type Input = HashMap<String, u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<HashMap<String, u32>, ParseError> {
+        directory_sizes(input)
+    }
+
+    /// Sum of the sizes of all directories no larger than `SIZE_THRESHOLD`.
+    fn part_one(directory_sizes: &HashMap<String, u32>) -> u32 {
+        directory_sizes
+            .values()
+            .filter(|s| **s <= SIZE_THRESHOLD)
+            .sum::<u32>()
+    }
+
+    /// Size of the smallest directory which frees up enough space for the update when deleted.
+    fn part_two(directory_sizes: &HashMap<String, u32>) -> u32 {
+        let used_space = directory_sizes["/"];
+        let remaining_space = TOTAL_SPACE - used_space;
+        let min_space_to_free = REQUIRED_SPACE - remaining_space;
+        let mut values: Vec<u32> = directory_sizes.values().copied().collect();
+        values.sort();
+        values
+            .into_iter()
+            .find(|s| *s >= min_space_to_free)
+            .unwrap()
+    }
+}
+
+/// Total size of each directory, keyed by path.
+fn directory_sizes(input: &str) -> Result<HashMap<String, u32>, ParseError> {
+    let mut cur_path: Vec<String> = Vec::new();
+    let mut directory_sizes: HashMap<String, u32> = HashMap::new();
+    for line in error::lines(input) {
+        if line.text.starts_with("$ ls") || line.text.starts_with("dir") { /* Noop */
+        } else if line.text.starts_with("$ cd ") {
+            // Handle change directory
+            cur_path = handle_cd(cur_path, line.text);
+        } else if line.text.starts_with(|c: char| c.is_ascii_digit()) {
+            // Handle file listing
+            let size_token = line.text.split(' ').next().unwrap_or_default();
+            let size: u32 = line.parse(size_token, "a file size")?;
+            for i in 0..cur_path.len() {
+                let sub_path = &cur_path[0..=i];
+                let path_name = sub_path.join("/");
+                let directory_size = directory_sizes.entry(path_name).or_default();
+                *directory_size += size;
+            }
+        } else {
+            return Err(line.error(1, "'$ cd', '$ ls', 'dir' or a file size"));
+        }
+    }
+    Ok(directory_sizes)
+}
+
+fn handle_cd(mut cur_path: Vec<String>, line: &str) -> Vec<String> {
+    // Change current directory
+    let new_dir = &line[5..];
+    if new_dir == ".." {
+        cur_path.pop();
+    } else {
+        cur_path.push(new_dir.to_string());
+    }
+    cur_path
+}
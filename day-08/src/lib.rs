@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use aoc_common::{ParseError, Solution};
+use aoc_grid::{DenseGrid, Pos};
+
+pub struct Day08;
+
+/// Grid of tree heights.
+pub type Forest = DenseGrid<u32>;
+
+impl Solution for Day08 {
+    type Input = Forest;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Forest, ParseError> {
+        DenseGrid::parse(input, |c| c.to_digit(10), "a tree height (0-9)")
+    }
+
+    /// Number of trees visible from outside the grid.
+    fn part_one(forest: &Forest) -> usize {
+        let (width, height) = (forest.width() as i32, forest.height() as i32);
+        // # Scan each row and column from both ends, starting just outside the grid
+        let scans = (0..height)
+            .flat_map(|y| {
+                [
+                    (Pos::new(-1, y), Pos::RIGHT),
+                    (Pos::new(width, y), Pos::LEFT),
+                ]
+            })
+            .chain(
+                (0..width)
+                    .flat_map(|x| [(Pos::new(x, -1), Pos::DOWN), (Pos::new(x, height), Pos::UP)]),
+            );
+        let mut visible = HashSet::new();
+        for (start, direction) in scans {
+            let mut min_visible_height = 0;
+            for (pos, &tree_height) in forest.ray(start, direction) {
+                if tree_height >= min_visible_height {
+                    min_visible_height = tree_height + 1;
+                    visible.insert(pos);
+                }
+            }
+        }
+        visible.len()
+    }
+
+    /// Highest scenic score of any tree.
+    fn part_two(forest: &Forest) -> usize {
+        forest
+            .iter()
+            .map(|(pos, &cur_height)| {
+                // Product of the view distances in each direction
+                Pos::ORTHOGONAL
+                    .iter()
+                    .map(|&direction| {
+                        let mut view_distance = 0;
+                        for (_, &search_height) in forest.ray(pos, direction) {
+                            view_distance += 1;
+                            if search_height >= cur_height {
+                                break;
+                            }
+                        }
+                        view_distance
+                    })
+                    .product()
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    error::{self, Line},
+    ParseError, Solution, Vec2,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Movement>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Movement>, ParseError> {
+        error::lines(input).map(parse_line).collect()
+    }
+
+    /// Number of positions visited by the tail of a rope with 2 knots.
+    fn part_one(movements: &Vec<Movement>) -> usize {
+        simulate_rope::<2>(movements)
+    }
+
+    /// Number of positions visited by the tail of a rope with 10 knots.
+    fn part_two(movements: &Vec<Movement>) -> usize {
+        simulate_rope::<10>(movements)
+    }
+}
+
+/// Simulates a rope with `KNOT_COUNT` knots, returning the number of unique positions occupied by
+/// the tail.
+fn simulate_rope<const KNOT_COUNT: usize>(movements: &[Movement]) -> usize {
+    let mut knot_positions = [Vec2::ZERO; KNOT_COUNT];
+    let mut tail_positions: HashSet<Vec2> = HashSet::new();
+    for movement in movements {
+        for _ in 0..movement.count {
+            knot_positions[0] += movement.direction;
+            for knot_idx in 1..knot_positions.len() {
+                let cur = knot_positions[knot_idx];
+                let prev = knot_positions[knot_idx - 1];
+                // Knots which are no longer touching move one step (possibly diagonally) closer
+                if prev.chebyshev_distance(cur) > 1 {
+                    knot_positions[knot_idx] += (prev - cur).signum();
+                }
+            }
+            tail_positions.insert(*knot_positions.last().unwrap());
+        }
+    }
+    tail_positions.len()
+}
+
+fn parse_line(line: Line) -> Result<Movement, ParseError> {
+    let mut tokens = line.text.split(' ');
+    let direction = match tokens.next() {
+        Some("U") => Vec2::UP,
+        Some("D") => Vec2::DOWN,
+        Some("L") => Vec2::LEFT,
+        Some("R") => Vec2::RIGHT,
+        _ => return Err(line.error(1, "'U', 'D', 'L' or 'R'")),
+    };
+    let count_token = line.expect(tokens.next(), "' '")?;
+    let count: u32 = line.parse(count_token, "a step count")?;
+    if tokens.next().is_some() {
+        let col = line.column_of(count_token) + count_token.len();
+        return Err(line.error(col, "end of line"));
+    }
+    Ok(Movement { direction, count })
+}
+
+#[derive(Debug, Clone)]
+pub struct Movement {
+    direction: Vec2,
+    count: u32,
+}
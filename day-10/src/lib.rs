@@ -0,0 +1,80 @@
+use aoc_common::{
+    error::{self, Line},
+    ParseError, Solution,
+};
+
+const INSTR_LATENCY: i32 = 2;
+const SCREEN_WIDTH: i32 = 40;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    /// `(cycles, increment)` of each instruction.
+    type Input = Vec<(i32, i32)>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+        error::lines(input).map(parse_line).collect()
+    }
+
+    /// Sum of the signal strengths during the 20th, 60th, 100th, 140th, 180th and 220th cycles.
+    fn part_one(instrs: &Vec<(i32, i32)>) -> i32 {
+        let mut cycle = 0;
+        let mut x = 1;
+        let mut signal_strength_sum = 0;
+        for &(cycles, increment) in instrs {
+            for _ in 0..cycles {
+                cycle += 1;
+                if cycle <= 220 && cycle % SCREEN_WIDTH == 20 {
+                    signal_strength_sum += cycle * x;
+                }
+            }
+            x += increment;
+        }
+        signal_strength_sum
+    }
+
+    /// Image rendered on the CRT, one line per screen row.
+    fn part_two(instrs: &Vec<(i32, i32)>) -> String {
+        let mut screen = String::new();
+        let mut current_column: i32 = 0;
+        let mut x = 1;
+        for &(cycles, increment) in instrs {
+            for _ in 0..cycles {
+                if (x - current_column).abs() > 1 {
+                    screen.push('.');
+                } else {
+                    screen.push('#');
+                }
+                current_column += 1;
+                if current_column >= SCREEN_WIDTH {
+                    current_column = 0;
+                    screen.push('\n');
+                }
+            }
+            x += increment;
+        }
+        screen.truncate(screen.trim_end().len());
+        screen
+    }
+}
+
+/// Parses an instruction into `(cycles, increment)`.
+fn parse_line(line: Line) -> Result<(i32, i32), ParseError> {
+    let mut tokens = line.text.split(' ');
+    match tokens.next() {
+        Some("addx") => {
+            let increment_token = line.expect(tokens.next(), "' '")?;
+            let increment = line.parse(increment_token, "an integer")?;
+            if tokens.next().is_some() {
+                let col = line.column_of(increment_token) + increment_token.len();
+                return Err(line.error(col, "end of line"));
+            }
+            Ok((INSTR_LATENCY, increment))
+        }
+        Some("noop") if tokens.next().is_none() => Ok((1, 0)),
+        Some("noop") => Err(line.error(5, "end of line")),
+        _ => Err(line.error(1, "'addx' or 'noop'")),
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+
+use aoc_common::{
+    error::{self, Line},
+    ParseError, Solution,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        parse(input)
+    }
+
+    /// Level of monkey business after 20 rounds, with worry levels divided by 3 after inspection.
+    fn part_one(monkeys: &Vec<Monkey>) -> usize {
+        let mut monkeys = monkeys.clone();
+        simulate(&mut monkeys, 20, |worry| worry / 3);
+        monkey_business(&monkeys)
+    }
+
+    /// Level of monkey business after 10000 rounds, without worry levels being divided.
+    fn part_two(monkeys: &Vec<Monkey>) -> usize {
+        let mut monkeys = monkeys.clone();
+        // Worry levels are only ever tested for divisibility, so they can be kept in check by
+        // working modulo the product of all divisors.
+        let modulus: i64 = monkeys.iter().map(|m| m.divisor).product();
+        simulate(&mut monkeys, 10000, |worry| worry % modulus);
+        monkey_business(&monkeys)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut lines = error::lines(input);
+    let mut monkeys = Vec::new();
+    // Targets are checked once the number of monkeys is known
+    let mut targets = Vec::new();
+    // ## Parse each Monkey
+    while let Some(line) = lines.next() {
+        line.strip_prefix("Monkey ")?;
+
+        // ### Parse starting items
+        let (line, rest) = next_line(input, &mut lines, "  Starting items: ")?;
+        let mut starting_items = VecDeque::new();
+        for item in rest.split(',') {
+            let item = item.trim();
+            starting_items.push_back(line.parse(item, "a worry level")?);
+        }
+
+        // ### Parse operation
+        let (line, rest) = next_line(input, &mut lines, "  Operation: new = old ")?;
+        let mut tokens = rest.split_ascii_whitespace();
+        let operator = line.expect(tokens.next(), "'+' or '*'")?;
+        let operand = line.expect(tokens.next(), "an integer or 'old'")?;
+        let monkey_op = if operand.eq("old") {
+            Op {
+                exponent: 2,
+                multiply: 1,
+                add: 0,
+            }
+        } else if operator.eq("+") {
+            Op {
+                exponent: 1,
+                multiply: 1,
+                add: line.parse(operand, "an integer or 'old'")?,
+            }
+        } else if operator.eq("*") {
+            Op {
+                exponent: 1,
+                multiply: line.parse(operand, "an integer or 'old'")?,
+                add: 0,
+            }
+        } else {
+            return Err(line.error_at(operator, "'+' or '*'"));
+        };
+
+        // ### Parse divisor
+        let (line, rest) = next_line(input, &mut lines, "  Test: divisible by ")?;
+        let divisor = match line.parse(rest, "a positive divisor")? {
+            divisor if divisor > 0 => divisor,
+            _ => return Err(line.error_at(rest, "a positive divisor")),
+        };
+
+        // ### Parse Target A
+        let (line, rest) = next_line(input, &mut lines, "    If true: throw to monkey ")?;
+        let target_on_success = line.parse(rest, "a monkey number")?;
+        targets.push((line, rest, target_on_success));
+
+        // ### Parse Target B
+        let (line, rest) = next_line(input, &mut lines, "    If false: throw to monkey ")?;
+        let target_on_fail = line.parse(rest, "a monkey number")?;
+        targets.push((line, rest, target_on_fail));
+
+        monkeys.push(Monkey {
+            items: starting_items,
+            operation: monkey_op,
+            divisor,
+            target_on_success,
+            target_on_fail,
+            inspected_cnt: 0,
+        });
+        lines.next();
+    }
+
+    for (line, token, target) in targets {
+        if target >= monkeys.len() {
+            return Err(line.error_at(
+                token,
+                format!("a monkey number less than {}", monkeys.len()),
+            ));
+        }
+    }
+    Ok(monkeys)
+}
+
+/// Returns the next line, and the rest of that line after `prefix`.
+fn next_line<'a>(
+    input: &str,
+    lines: &mut impl Iterator<Item = Line<'a>>,
+    prefix: &str,
+) -> Result<(Line<'a>, &'a str), ParseError> {
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, format!("'{}'", prefix.trim())))?;
+    Ok((line, line.strip_prefix(prefix)?))
+}
+
+fn simulate(monkeys: &mut [Monkey], round_cnt: usize, relief: impl Fn(i64) -> i64) {
+    for _ in 0..round_cnt {
+        for monkey_idx in 0..monkeys.len() {
+            monkeys[monkey_idx].inspected_cnt += monkeys[monkey_idx].items.len();
+            for _ in 0..monkeys[monkey_idx].items.len() {
+                let monkey = &mut monkeys[monkey_idx];
+                let op = &monkey.operation;
+                let item = monkey.items.pop_front().unwrap();
+                let new_value = relief(item.pow(op.exponent) * op.multiply + op.add);
+                let new_monkey_idx = if new_value % monkeys[monkey_idx].divisor == 0 {
+                    monkeys[monkey_idx].target_on_success
+                } else {
+                    monkeys[monkey_idx].target_on_fail
+                };
+                monkeys[new_monkey_idx].items.push_back(new_value);
+            }
+        }
+    }
+}
+
+/// Product of the inspection counts of the 2 most active monkeys.
+fn monkey_business(monkeys: &[Monkey]) -> usize {
+    let mut inspected_cnts: Vec<usize> = monkeys.iter().map(|m| m.inspected_cnt).collect();
+    inspected_cnts.sort();
+    inspected_cnts.iter().rev().take(2).product()
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<i64>,
+    operation: Op,
+    divisor: i64,
+    target_on_success: usize,
+    target_on_fail: usize,
+    inspected_cnt: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Op {
+    exponent: u32,
+    multiply: i64,
+    add: i64,
+}
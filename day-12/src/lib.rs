@@ -0,0 +1,96 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::{ParseError, Solution};
+use aoc_grid::{DenseGrid, Pos};
+
+pub struct Day12;
+
+pub struct HeightMap {
+    heights: DenseGrid<i32>,
+    start: Pos,
+    end: Pos,
+}
+
+impl Solution for Day12 {
+    type Input = HeightMap;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<HeightMap, ParseError> {
+        HeightMap::parse(input)
+    }
+
+    /// Fewest steps required to move from the start position to the end position.
+    fn part_one(height_map: &HeightMap) -> i32 {
+        bfs(height_map, height_map.start, 1, &|pos| {
+            pos == height_map.end
+        })
+    }
+
+    /// Fewest steps required to move from any square with elevation `a` to the end position.
+    fn part_two(height_map: &HeightMap) -> i32 {
+        bfs(height_map, height_map.end, -1, &|pos| {
+            height_map.heights[pos] == 0
+        })
+    }
+}
+
+fn bfs(
+    height_map: &HeightMap,
+    start: Pos,
+    max_step: i32,
+    end_condition: &dyn Fn(Pos) -> bool,
+) -> i32 {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    queue.push_back((start, 0));
+    visited.insert(start);
+    while let Some((cur_pos, path_len)) = queue.pop_front() {
+        if end_condition(cur_pos) {
+            return path_len;
+        };
+        let cur_height = height_map.heights[cur_pos];
+        // ## Push adjacent positions to queue
+        for (new_pos, &new_height) in height_map.heights.neighbours4(cur_pos) {
+            // Ignore this position if it has already been visited
+            if visited.contains(&new_pos) {
+                continue;
+            }
+            if (new_height - cur_height) * max_step.signum() > max_step.abs() {
+                continue;
+            }
+            visited.insert(new_pos);
+            queue.push_back((new_pos, path_len + 1));
+        }
+    }
+    panic!();
+}
+
+impl HeightMap {
+    fn parse(data: &str) -> Result<HeightMap, ParseError> {
+        let squares = DenseGrid::parse(
+            data,
+            |c| matches!(c, 'S' | 'E' | 'a'..='z').then_some(c),
+            "'S', 'E' or an elevation (a-z)",
+        )?;
+        let start = squares
+            .position(|&c| c == 'S')
+            .ok_or_else(|| ParseError::end_of_input(data, "a start position 'S'"))?;
+        let end = squares
+            .position(|&c| c == 'E')
+            .ok_or_else(|| ParseError::end_of_input(data, "a best signal position 'E'"))?;
+        let heights = squares.map(|&c| {
+            let c = match c {
+                'S' => 'a',
+                'E' => 'z',
+                c => c,
+            };
+            (u32::from(c) - u32::from('a')) as i32
+        });
+        Ok(HeightMap {
+            heights,
+            start,
+            end,
+        })
+    }
+}
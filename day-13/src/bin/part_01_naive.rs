@@ -1,4 +1,10 @@
-use std::{cmp::Ordering, fs, iter::Peekable, str::Chars};
+use std::cmp::Ordering;
+
+use aoc_common::{
+    input::{exit_with_error, source_from_args},
+    parse::{self, int, list, literal, or, Cursor},
+    ParseError,
+};
 
 #[derive(Debug)]
 enum Value {
@@ -8,65 +14,47 @@ enum Value {
 
 fn main() {
     const ITER_COUNT: usize = 10_000;
+    let source = source_from_args("input.txt");
+    let input = source
+        .read()
+        .unwrap_or_else(|err| exit_with_error(&err.to_string()));
     let mut total = 0;
     for _ in 0..ITER_COUNT {
-        total += run();
+        total += run(&input).unwrap_or_else(|err| exit_with_error(&format!("{source}:{err}")));
     }
     println!("{}", total);
 }
 
-fn run() -> i32 {
-    let input = fs::read_to_string("input.txt").unwrap();
-    let mut lines = input.lines();
+fn run(input: &str) -> Result<usize, ParseError> {
     let mut correct_order_index_sum = 0;
-    let mut i = 0;
-    loop {
-        let a = Value::parse_list(&mut lines.next().unwrap().chars().peekable());
-        let b = Value::parse_list(&mut lines.next().unwrap().chars().peekable());
+    for (i, pair) in parse::records(input).enumerate() {
+        let (a, b) = match pair[..] {
+            [a, b] => (a, b),
+            [first] => {
+                return Err(ParseError::new(
+                    first.number + 1,
+                    1,
+                    "the second packet of the pair",
+                ))
+            }
+            _ => return Err(pair[2].error(1, "a blank line")),
+        };
+        let a = parse::parse_line(a, Value::parse_list)?;
+        let b = parse::parse_line(b, Value::parse_list)?;
         if a.cmp(&b) != Ordering::Greater {
             correct_order_index_sum += i + 1;
         }
-        if lines.next().is_none() {
-            break;
-        }
-        i += 1;
     }
-    correct_order_index_sum
+    Ok(correct_order_index_sum)
 }
 
 impl Value {
-    fn parse_list(chars: &mut Peekable<Chars>) -> Value {
-        let opening_bracket = chars.next().unwrap();
-        assert!(opening_bracket == '[');
-        let mut result = Vec::new();
-        loop {
-            match chars.peek() {
-                Some(']') => {
-                    chars.next();
-                    return Value::List(result);
-                }
-                Some('[') => result.push(Value::parse_list(chars)),
-                Some(',') => {
-                    chars.next();
-                }
-                Some(_) => result.push(Value::parse_integer(chars)),
-                _ => panic!(),
-            }
-        }
-    }
-
-    fn parse_integer(chars: &mut Peekable<Chars>) -> Value {
-        let mut buffer = String::new();
-        loop {
-            match chars.peek() {
-                Some(c) if c.is_ascii_digit() => {
-                    buffer.push(*c);
-                    chars.next();
-                }
-                Some(_) => return Value::Integer(buffer.parse().unwrap()),
-                _ => panic!(),
-            }
-        }
+    fn parse_list(cursor: &mut Cursor) -> Result<Value, ParseError> {
+        let value = or(
+            |c: &mut Cursor| int(c).map(Value::Integer),
+            Value::parse_list,
+        );
+        list(literal("["), value, literal(","), literal("]"))(cursor).map(Value::List)
     }
 
     fn cmp(&self, other: &Self) -> Ordering {
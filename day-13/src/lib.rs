@@ -2,21 +2,107 @@ use std::cmp::Ordering;
 use std::iter::Peekable;
 use std::str::Chars;
 
+use aoc_common::{
+    parse::{self, int, list, literal, or, Cursor},
+    ParseError, Solution,
+};
+
+const DIVIDER_1: &str = "[[2]]";
+const DIVIDER_2: &str = "[[6]]";
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    /// Packets, in the order they appear in the input.
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        let mut packets = Vec::new();
+        for pair in parse::records(input) {
+            match pair[..] {
+                [_, _] => {}
+                [first] => {
+                    return Err(ParseError::new(
+                        first.number + 1,
+                        1,
+                        "the second packet of the pair",
+                    ))
+                }
+                _ => return Err(pair[2].error(1, "a blank line")),
+            }
+            for line in pair {
+                parse::parse_line(line, check_packet)?;
+                packets.push(line.text.to_string());
+            }
+        }
+        Ok(packets)
+    }
+
+    /// Sum of the indices of the pairs of packets which are in the right order.
+    fn part_one(packets: &Vec<String>) -> usize {
+        let mut correct_order_index_sum = 0;
+        for (i, pair) in packets.chunks(2).enumerate() {
+            if compare_packets(&pair[0], &pair[1]) != Ordering::Greater {
+                correct_order_index_sum += i + 1;
+            }
+        }
+        correct_order_index_sum
+    }
+
+    /// Decoder key: the product of the indices of the divider packets once all packets are
+    /// sorted.
+    fn part_two(packets: &Vec<String>) -> usize {
+        let mut packets: Vec<&str> = packets.iter().map(String::as_str).collect();
+        packets.extend([DIVIDER_1, DIVIDER_2]);
+        packets.sort_by(|a, b| compare_packets(a, b));
+        packets
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| (**p).eq(DIVIDER_1) || (**p).eq(DIVIDER_2))
+            .map(|(i, _)| i + 1)
+            .product()
+    }
+}
+
+/// Checks that the cursor is at a well formed packet: a list of integers and nested lists.
+fn check_packet(cursor: &mut Cursor) -> Result<(), ParseError> {
+    let value = or(|c: &mut Cursor| int::<u32>(c).map(drop), check_packet);
+    list(literal("["), value, literal(","), literal("]"))(cursor).map(drop)
+}
+
 pub fn compare_packets(a: &str, b: &str) -> Ordering {
     let mut chars_a = a.chars().peekable();
     let mut chars_b = b.chars().peekable();
     loop {
+        // If a packet has been consumed without finding a difference, it is the shorter packet
+        match (chars_a.peek(), chars_b.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(_), Some(_)) => {}
+        }
         let a_value = next_value(&mut chars_a);
         let b_value = next_value(&mut chars_b);
         let ordering = a_value.0.cmp(&b_value.0);
         if ordering == Ordering::Equal {
+            if a_value.0 == -1 && a_value.1 != b_value.1 {
+                // Both values are the end of a list, but one of them ended inside an extra
+                // nested list. That packet contains more items.
+                return a_value.1.cmp(&b_value.1);
+            }
             if a_value.1 > b_value.1 {
                 for _ in 0..a_value.1 - b_value.1 {
-                    if next_value(&mut chars_a).0 != -1 { return Ordering::Greater }
+                    if next_value(&mut chars_a).0 != -1 {
+                        return Ordering::Greater;
+                    }
                 }
             } else if b_value.1 > a_value.1 {
                 for _ in 0..b_value.1 - a_value.1 {
-                    if next_value(&mut chars_b).0 != -1 { return Ordering::Less }
+                    if next_value(&mut chars_b).0 != -1 {
+                        return Ordering::Less;
+                    }
                 }
             }
         }
@@ -1,152 +1,174 @@
-use std::{collections::HashMap, iter::Peekable};
+use aoc_common::{
+    error,
+    parse::{self, int, label, literal, separated, Cursor},
+    ParseError, Solution,
+};
+use aoc_grid::{Pos, SparseGrid};
 
-pub const ORIGIN: Vec2 = Vec2 { x: 500, y: 0 };
+pub const ORIGIN: Pos = Pos::new(500, 0);
 
-fn read_point(chars: &mut Peekable<impl Iterator<Item = char>>) -> Option<Vec2> {
-    let mut read_int = || {
-        // Consume all non int characters
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::parse(input)
+    }
+
+    fn part_one(map: &Map) -> usize {
+        grains_until_abyss(map)
+    }
+
+    fn part_two(map: &Map) -> usize {
+        grains_until_source_blocked(map)
+    }
+}
+
+/// Number of grains of sand which come to rest before sand starts falling into the abyss.
+fn grains_until_abyss(map: &Map) -> usize {
+    let mut map = map.clone();
+    let max_y = map.max_y();
+
+    // Simulate a single grain of sand. Returns whether the grain of sand fell of the edge of the
+    // map.
+    let mut simulate_grain = || {
+        let mut pos = ORIGIN;
         loop {
-            match chars.peek() {
-                Some(c) if c.is_ascii_digit() => break,
-                Some(_) => {
-                    chars.next();
-                }
-                None => break,
+            if pos.y > max_y {
+                return true;
             }
-        }
-        let mut has_result = false;
-        let mut result = 0;
-        loop {
-            let c = chars.next();
-            match c {
-                None => return if has_result { Some(result) } else { None },
-                Some(c) if !c.is_ascii_digit() => {
-                    return if has_result { Some(result) } else { None }
-                }
-                Some(c) => {
-                    has_result = true;
-                    result *= 10;
-                    result += i32::try_from(c.to_digit(10).unwrap()).unwrap();
+            let candidate_positions =
+                [pos + Pos::DOWN, pos + Pos::DOWN_LEFT, pos + Pos::DOWN_RIGHT];
+            let mut has_moved = false;
+            for &candidate_position in &candidate_positions {
+                if !map.cells.contains(candidate_position) {
+                    has_moved = true;
+                    pos = candidate_position;
+                    break;
                 }
             }
+            if !has_moved {
+                map.cells.insert(pos, 'o');
+                return false;
+            }
         }
     };
 
-    if let Some(int) = read_int() {
-        let p = Vec2 {
-            x: int,
-            y: read_int().unwrap(),
-        };
-        Some(p)
-    } else {
-        None
+    let mut grains = 0;
+    while !simulate_grain() {
+        grains += 1;
     }
+    grains
 }
 
-#[derive(Debug, Eq, Hash, PartialEq, Clone)]
-pub struct Vec2 {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl Vec2 {
-    pub const DOWN: Vec2 = Vec2 { x: 0, y: 1 };
-    pub const DOWN_LEFT: Vec2 = Vec2 { x: -1, y: 1 };
-    pub const DOWN_RIGHT: Vec2 = Vec2 { x: 1, y: 1 };
+/// Number of grains of sand which come to rest before the source of the sand is blocked, given
+/// an infinite floor 2 units below the lowest rock.
+fn grains_until_source_blocked(map: &Map) -> usize {
+    let mut map = map.clone();
+    let floor_y = map.max_y() + 2;
 
-    pub fn min(&self, other: &Vec2) -> Vec2 {
-        Vec2 {
-            x: self.x.min(other.x),
-            y: self.y.min(other.y),
+    // Simulate a single grain of sand until it comes to rest.
+    let simulate_grain = |map: &mut Map| {
+        let mut pos = ORIGIN;
+        loop {
+            let candidate_positions =
+                [pos + Pos::DOWN, pos + Pos::DOWN_LEFT, pos + Pos::DOWN_RIGHT];
+            let mut has_moved = false;
+            for &candidate_position in &candidate_positions {
+                let blocked =
+                    map.cells.contains(candidate_position) || candidate_position.y >= floor_y;
+                if !blocked {
+                    has_moved = true;
+                    pos = candidate_position;
+                    break;
+                }
+            }
+            if !has_moved {
+                map.cells.insert(pos, 'o');
+                return;
+            }
         }
-    }
+    };
 
-    pub fn max(&self, other: &Vec2) -> Vec2 {
-        Vec2 {
-            x: self.x.max(other.x),
-            y: self.y.max(other.y),
-        }
+    let mut grains = 0;
+    while !map.cells.contains(ORIGIN) {
+        simulate_grain(&mut map);
+        grains += 1;
     }
+    grains
+}
 
-    pub fn add(&self, other: &Vec2) -> Vec2 {
-        Vec2 {
-            x: self.x + other.x,
-            y: self.y + other.y,
+/// Parses a point of the form "498,4".
+fn parse_point(cursor: &mut Cursor) -> Result<Pos, ParseError> {
+    let x = label(int, "an x coordinate")(cursor)?;
+    literal(",")(cursor)?;
+    let y = label(int, "a y coordinate")(cursor)?;
+    Ok(Pos::new(x, y))
+}
+
+/// Parses a rock path of the form "498,4 -> 498,6 -> 496,6", where each point must be in line
+/// with the previous one.
+fn parse_path<'a>(cursor: &mut Cursor<'a>) -> Result<Vec<Pos>, ParseError> {
+    let point = |cursor: &mut Cursor<'a>| {
+        let start = *cursor;
+        parse_point(cursor).map(|point| (start, point))
+    };
+    let points = separated(point, literal(" -> "))(cursor)?;
+    for pair in points.windows(2) {
+        let [(_, prev), (start, point)] = pair else {
+            unreachable!()
+        };
+        if prev.x != point.x && prev.y != point.y {
+            return Err(start.error("a point in line with the previous point"));
         }
     }
+    Ok(points.into_iter().map(|(_, point)| point).collect())
 }
 
-#[derive(Debug)]
+/// Rock (`#`) and sand which has come to rest (`o`). The bounds of the map always include the
+/// source of the sand.
+#[derive(Debug, Clone)]
 pub struct Map {
-    pub min: Vec2,
-    pub max: Vec2,
-    pub points: HashMap<Vec2, char>,
+    pub cells: SparseGrid<char>,
 }
 
 impl Map {
-    pub fn new(min: Vec2, max: Vec2) -> Map {
-        Map {
-            min,
-            max,
-            points: HashMap::new(),
-        }
-    }
-
-    pub fn parse(input: &str) -> Map {
-        let mut min_bound = ORIGIN;
-        let mut max_bound = ORIGIN;
-        let mut sections: Vec<Vec<Vec2>> = Vec::new();
-        for line in input.lines() {
-            let mut points = Vec::new();
-            let mut chars = line.chars().peekable();
-            loop {
-                let point = read_point(&mut chars);
-                if point.is_none() {
-                    break;
-                }
-                let point = point.unwrap();
-                min_bound = min_bound.min(&point);
-                max_bound = max_bound.max(&point);
-                points.push(point);
-            }
-            sections.push(points);
-        }
-
-        let mut map = Map::new(min_bound, max_bound);
-        for section in sections {
-            for segment in section.windows(2) {
-                map.add_segment(&segment[0], &segment[1]);
+    /// Parses rock paths of the form "498,4 -> 498,6 -> 496,6", one per line.
+    pub fn parse(input: &str) -> Result<Map, ParseError> {
+        let mut map = Map {
+            cells: SparseGrid::new(),
+        };
+        map.cells.include(ORIGIN);
+        for line in error::lines(input) {
+            let path = parse::parse_line(line, parse_path)?;
+            map.add_segment(path[0], path[0]);
+            for segment in path.windows(2) {
+                map.add_segment(segment[0], segment[1]);
             }
         }
-        map
+        Ok(map)
     }
 
-    pub fn add_segment(&mut self, a: &Vec2, b: &Vec2) {
+    /// Adds rock at every position in the rectangle with corners `a` and `b`.
+    pub fn add_segment(&mut self, a: Pos, b: Pos) {
         let min = a.min(b);
         let max = a.max(b);
         for x in min.x..=max.x {
             for y in min.y..=max.y {
-                let p = Vec2 { x, y };
-                self.add_point(p, '#');
+                self.cells.insert(Pos::new(x, y), '#');
             }
         }
     }
 
-    pub fn add_point(&mut self, p: Vec2, c: char) {
-        self.min = self.min.min(&p);
-        self.max = self.max.max(&p);
-        self.points.insert(p, c);
+    /// Lowest row within the bounds of the map.
+    pub fn max_y(&self) -> i32 {
+        self.cells.bounds().map_or(ORIGIN.y, |(_, max)| max.y)
     }
 
     pub fn print(&self) {
-        for y in self.min.y..=self.max.y {
-            for x in self.min.x..=self.max.x {
-                match self.points.get(&Vec2 { x, y }) {
-                    Some(c) => print!("{}", c),
-                    None => print!("."),
-                }
-            }
-            println!();
-        }
+        println!("{}", self.cells.render('.', |&c| c));
     }
 }
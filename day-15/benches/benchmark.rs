@@ -1,60 +1,96 @@
+use std::fmt::Display;
+
 use criterion::{criterion_group, criterion_main, Criterion};
-use day_15::border_intersection;
-use day_15::column_skipping;
-use day_15::range_exclusion;
-use day_15::range_exclusion::aabb_from_sensor;
+use day_15::border_intersection::BorderIntersection;
+use day_15::solver::{solvers, Part2Solver};
+use day_15::sweep_line::SweepLine;
 use day_15::test_case;
-use day_15::test_case::TestCase;
+use day_15::{generator, test_case::TestCase};
+
+/// Prints the work `solver` does on `input`, labelled like the benchmark with the given
+/// parameter, so the timings can be explained by it.
+fn report_stats(solver: &dyn Part2Solver, input: &TestCase, parameter: impl Display) {
+    let (_, stats) = solver.solve_with_stats(&input.sensors, input.dimension);
+    println!("{}/{parameter}: {stats}", solver.name());
+}
 
 pub fn bench_algorithms(c: &mut Criterion) {
-    let inputs = vec![
-        TestCase::from(&test_case::AOC_ACTUAL),
-        TestCase::from(&test_case::AOC_EXAMPLE),
-    ];
+    let generated = generator::generate(4_000_000, 100, 0).unwrap();
+    let mut inputs = test_case::file_test_cases();
+    inputs.push(TestCase::from(&generated));
 
     let mut group = c.benchmark_group("Algorithm");
     for input in inputs.iter() {
-        group.bench_with_input(
-            criterion::BenchmarkId::new("00. Brute Force", input.name),
-            &input,
-            |bencher, input| {
-                let mut sensors = input.sensors.clone();
-                bencher.iter(|| if input.name != test_case::AOC_ACTUAL.name { day_15::brute_force::solve(&mut sensors, input.dimension); });
-            },
-        );
-
-        group.bench_with_input(
-            criterion::BenchmarkId::new("01. Column Skipping", input.name),
-            &input,
-            |bencher, input| {
-                let mut sensors = input.sensors.clone();
-                bencher.iter(|| column_skipping::solve(&mut sensors, input.dimension));
-            },
-        );
-
-        group.bench_with_input(
-            criterion::BenchmarkId::new("02. Range Exclusion", input.name),
-            &input,
-            |bencher, input| {
-                let mut sensors = input
-                    .sensors
-                    .iter()
-                    .map(|s| aabb_from_sensor(s, input.dimension))
-                    .collect();
-                bencher.iter(|| range_exclusion::solve(&mut sensors, input.dimension));
-            },
-        );
-
-        group.bench_with_input(
-            criterion::BenchmarkId::new("03. Border Intersection", input.name),
-            &input,
-            |bencher, input| {
-                let mut sensors = input.sensors.clone();
-                bencher.iter(|| border_intersection::solve(&mut sensors, input.dimension));
-            },
-        );
+        for (idx, solver) in solvers().into_iter().enumerate() {
+            if !solver.is_practical(input.dimension) {
+                continue;
+            }
+            report_stats(solver, input, &input.name);
+            group.bench_with_input(
+                criterion::BenchmarkId::new(format!("{idx:02}. {}", solver.name()), &input.name),
+                &input,
+                |bencher, input| bencher.iter(|| solver.solve(&input.sensors, input.dimension)),
+            );
+        }
+    }
+}
+
+/// Compares the solvers which scale to many sensors on generated layouts with increasing numbers
+/// of sensors, in the actual puzzle's search area. Border intersection takes seconds with tens of
+/// thousands of sensors, so fewer samples are taken.
+pub fn bench_sensor_counts(c: &mut Criterion) {
+    let solvers: [&dyn Part2Solver; 2] = [&BorderIntersection, &SweepLine];
+
+    let mut group = c.benchmark_group("Sensor count");
+    group.sample_size(10);
+    for sensor_count in [100, 1_000, 10_000, 50_000] {
+        let layout = generator::generate(4_000_000, sensor_count, 0).unwrap();
+        let input = TestCase::from(&layout);
+        for solver in solvers {
+            report_stats(solver, &input, sensor_count);
+            group.bench_with_input(
+                criterion::BenchmarkId::new(solver.name(), sensor_count),
+                &input,
+                |bencher, input| bencher.iter(|| solver.solve(&input.sensors, input.dimension)),
+            );
+        }
+    }
+}
+
+/// Compares the multi-threaded solvers on the actual puzzle input with different numbers of
+/// threads.
+#[cfg(feature = "parallel")]
+pub fn bench_threads(c: &mut Criterion) {
+    use day_15::parallel::{ParallelBorderIntersection, ParallelColumnSkipping};
+
+    let input = TestCase::from(&test_case::AOC_ACTUAL);
+    let solvers: [&dyn Part2Solver; 2] = [&ParallelColumnSkipping, &ParallelBorderIntersection];
+
+    let mut group = c.benchmark_group("Threads");
+    for threads in [1, 2, 4, 8] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        for solver in solvers {
+            group.bench_with_input(
+                criterion::BenchmarkId::new(solver.name(), threads),
+                &input,
+                |bencher, input| {
+                    bencher.iter(|| pool.install(|| solver.solve(&input.sensors, input.dimension)))
+                },
+            );
+        }
     }
 }
 
-criterion_group!(benches, bench_algorithms);
+#[cfg(not(feature = "parallel"))]
+criterion_group!(benches, bench_algorithms, bench_sensor_counts);
+#[cfg(feature = "parallel")]
+criterion_group!(
+    benches,
+    bench_algorithms,
+    bench_sensor_counts,
+    bench_threads
+);
 criterion_main!(benches);
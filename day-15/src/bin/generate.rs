@@ -0,0 +1,45 @@
+//! Writes a random sensor layout with exactly one uncovered position, in the puzzle input format.
+//!
+//! ```shell
+//! $ cargo run --release --bin generate -- 4000000 30 1 test_cases/generated.txt
+//! ```
+
+use std::{env, fs};
+
+use aoc_common::input::exit_with_error;
+use day_15::generator;
+
+const USAGE: &str = "usage: generate <search size> <sensor count> <seed> [output path]";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (search_size, sensor_count, seed, output) = match &args[..] {
+        [search_size, sensor_count, seed] => (search_size, sensor_count, seed, None),
+        [search_size, sensor_count, seed, output] => {
+            (search_size, sensor_count, seed, Some(output))
+        }
+        _ => exit_with_error(USAGE),
+    };
+    let search_size = parse_arg(search_size, "search size");
+    let sensor_count = parse_arg(sensor_count, "sensor count");
+    let seed = parse_arg(seed, "seed");
+
+    let Some(layout) = generator::generate(search_size, sensor_count, seed) else {
+        exit_with_error(&format!(
+            "couldn't cover a search area of size {search_size} with {sensor_count} sensors"
+        ));
+    };
+    match output {
+        Some(path) => {
+            fs::write(path, layout.to_input())
+                .unwrap_or_else(|err| exit_with_error(&format!("failed to write {path}: {err}")));
+            eprintln!("Uncovered position: {:?}", layout.hole);
+        }
+        None => print!("{}", layout.to_input()),
+    }
+}
+
+fn parse_arg<T: std::str::FromStr>(arg: &str, name: &str) -> T {
+    arg.parse()
+        .unwrap_or_else(|_| exit_with_error(&format!("invalid {name} '{arg}'\n{USAGE}")))
+}
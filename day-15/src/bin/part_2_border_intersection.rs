@@ -1,9 +1,5 @@
-use day_15::{border_intersection::solve, test_case::FileTestCase};
-
-const TEST_CASE: FileTestCase = day_15::test_case::AOC_ACTUAL;
+use day_15::{border_intersection::BorderIntersection, solver};
 
 fn main() {
-    let mut sensors = day_15::parse(TEST_CASE.input);
-    let result = solve(&mut sensors, TEST_CASE.search_size);
-    dbg!(result);
+    solver::run(&BorderIntersection, "test_cases/aoc_actual.txt");
 }
@@ -1,7 +1,5 @@
-use day_15::{brute_force::solve, parse, test_case};
+use day_15::{brute_force::BruteForce, solver};
 
 fn main() {
-    let case = test_case::AOC_EXAMPLE;
-    let result = solve(&mut parse(case.input), case.search_size);
-    dbg!(result);
+    solver::run(&BruteForce, "test_cases/aoc_example.txt");
 }
\ No newline at end of file
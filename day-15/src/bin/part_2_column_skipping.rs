@@ -1,7 +1,5 @@
-use day_15::{column_skipping::solve, parse, test_case};
+use day_15::{column_skipping::ColumnSkipping, solver};
 
 fn main() {
-    let case = test_case::AOC_ACTUAL;
-    let result = solve(&mut parse(case.input), case.search_size);
-    dbg!(result);
+    solver::run(&ColumnSkipping, "test_cases/aoc_actual.txt");
 }
@@ -0,0 +1,5 @@
+use day_15::{solver, sweep_line::SweepLine};
+
+fn main() {
+    solver::run(&SweepLine, "test_cases/aoc_actual.txt");
+}
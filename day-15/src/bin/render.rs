@@ -0,0 +1,69 @@
+//! Renders the sensors' coverage of a puzzle input as an SVG, or as a PNG heat map of the search
+//! area, depending on the output file's extension.
+//!
+//! ```shell
+//! $ cargo run --release --bin render -- test_cases/aoc_actual.txt coverage.svg
+//! $ cargo run --release --bin render -- test_cases/aoc_actual.txt coverage.png 1000
+//! ```
+
+use std::{env, fs};
+
+use aoc_common::{
+    input::{exit_with_error, InputSource},
+    Solution,
+};
+use day_15::{
+    border_intersection::BorderIntersection, parse_header, render, solver::Part2Solver,
+    test_case::TestCase, Day15,
+};
+
+const USAGE: &str = "usage: render <input path> <output path (.svg or .png)> [PNG size]";
+
+/// Width and height of the PNG unless given on the command line.
+const DEFAULT_PNG_SIZE: u32 = 800;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (input_path, output, png_size) = match &args[..] {
+        [input_path, output] => (input_path, output, DEFAULT_PNG_SIZE),
+        [input_path, output, png_size] => (
+            input_path,
+            output,
+            png_size.parse().unwrap_or_else(|_| {
+                exit_with_error(&format!("invalid PNG size '{png_size}'\n{USAGE}"))
+            }),
+        ),
+        _ => exit_with_error(USAGE),
+    };
+
+    let source = InputSource::from(input_path.as_str());
+    let input = source
+        .read()
+        .unwrap_or_else(|err| exit_with_error(&err.to_string()));
+    let puzzle =
+        Day15::parse(&input).unwrap_or_else(|err| exit_with_error(&format!("{source}:{err}")));
+    // Inputs without a header naming the distress beacon are marked where border intersection
+    // finds an uncovered position.
+    let expected_pos = parse_header(&input)
+        .ok()
+        .flatten()
+        .and_then(|header| header.distress_beacon)
+        .or_else(|| BorderIntersection.solve(&puzzle.sensors, puzzle.search_size))
+        .unwrap_or_else(|| exit_with_error("every position in the search area is covered"));
+    let test_case = TestCase {
+        name: input_path.clone(),
+        sensors: puzzle.sensors,
+        dimension: puzzle.search_size,
+        expected_pos,
+    };
+
+    let image = if output.ends_with(".svg") {
+        render::svg(&test_case).into_bytes()
+    } else if output.ends_with(".png") {
+        render::heat_map(&test_case, png_size)
+    } else {
+        exit_with_error(USAGE)
+    };
+    fs::write(output, image)
+        .unwrap_or_else(|err| exit_with_error(&format!("failed to write {output}: {err}")));
+}
@@ -1,119 +1,215 @@
-use crate::{Aabb, Sensor, Vec2};
+use crate::{
+    solver::{Part2Solver, Stats},
+    Aabb, Coord, Sensor, Vec2,
+};
 
-pub fn solve(sensors: &mut Vec<Sensor>, dimension: i32) -> (Vec2, i64) {
+/// Checks the corners of the search area, and the intersections of the lines just outside the
+/// borders of the sensors' ranges with each other and with the edges of the search area.
+pub struct BorderIntersection;
+
+impl<T: Coord> Part2Solver<T> for BorderIntersection {
+    fn name(&self) -> &'static str {
+        "Border Intersection"
+    }
+
+    fn solve_with_stats(&self, sensors: &[Sensor<T>], search_size: T) -> (Option<Vec2<T>>, Stats) {
+        let mut stats = Stats::default();
+        let pos = search(sensors, search_size, &mut stats);
+        (pos, stats)
+    }
+}
+
+/// Returns `None` if there is no uncovered position in the search area, or any one of them if
+/// there are several.
+pub fn solve<T: Coord>(sensors: &[Sensor<T>], dimension: T) -> Option<Vec2<T>> {
+    search(sensors, dimension, &mut Stats::default())
+}
+
+fn search<T: Coord>(sensors: &[Sensor<T>], dimension: T, stats: &mut Stats) -> Option<Vec2<T>> {
+    if let Some(pos) = solve_on_edges(sensors, dimension, stats) {
+        return Some(pos);
+    }
+
+    // Find intersections and check for solutions
+    let (pos_slope_segments, neg_slope_segments) = border_segments(sensors);
+    pos_slope_segments.iter().find_map(|pos_slope_segment| {
+        neg_slope_segments.iter().find_map(|neg_slope_segment| {
+            solve_at_intersection(
+                pos_slope_segment,
+                neg_slope_segment,
+                sensors,
+                dimension,
+                stats,
+            )
+        })
+    })
+}
+
+/// Uncovered position in the corners of the search area, or where the borders of the sensors'
+/// ranges meet its edges.
+pub(crate) fn solve_on_edges<T: Coord>(
+    sensors: &[Sensor<T>],
+    dimension: T,
+    stats: &mut Stats,
+) -> Option<Vec2<T>> {
     // check for solutions in corners
     for point in &[
-        Vec2 { x: 0, y: 0 },
-        Vec2 { x: 0, y: dimension },
+        Vec2 {
+            x: T::ZERO,
+            y: T::ZERO,
+        },
+        Vec2 {
+            x: T::ZERO,
+            y: dimension,
+        },
         Vec2 {
             x: dimension,
             y: dimension,
         },
-        Vec2 { x: dimension, y: 0 },
+        Vec2 {
+            x: dimension,
+            y: T::ZERO,
+        },
     ] {
-        if let Some(solution) = is_solution(point, sensors.iter(), dimension) {
-            return solution;
+        if is_solution(point, sensors.iter(), stats) {
+            return Some(*point);
         }
     }
 
-    // Generate all border line segments from sensors
+    // Check where the borders meet the edges of the search area
+    for sensor in sensors.iter() {
+        for pos in edge_intersections(sensor, dimension) {
+            if is_solution(&pos, sensors.iter(), stats) {
+                return Some(pos);
+            }
+        }
+    }
+    None
+}
+
+/// Generate all border line segments from sensors, as the segments with a positive slope and
+/// those with a negative slope.
+pub(crate) fn border_segments<T: Coord>(
+    sensors: &[Sensor<T>],
+) -> (Vec<Segment<T>>, Vec<Segment<T>>) {
     let mut pos_slope_segments = Vec::new();
     let mut neg_slope_segment = Vec::new();
     for sensor in sensors.iter() {
         pos_slope_segments.extend(create_positive_slope_segments(sensor));
         neg_slope_segment.extend(create_negative_slope_segments(sensor));
     }
+    (pos_slope_segments, neg_slope_segment)
+}
 
-    // Find intersections and check for solutions
-    for pos_slope_segment in pos_slope_segments.iter() {
-        for neg_slope_segment in neg_slope_segment.iter() {
-            let intersection = segment_intersection(pos_slope_segment, neg_slope_segment);
-            if let Some((pos, is_in_center)) = intersection {
-                let solution_candidates = if is_in_center {
-                    [
-                        Some(pos.clone()),
-                        Some(Vec2 {
-                            x: pos.x + 1,
-                            y: pos.y,
-                        }),
-                        Some(Vec2 {
-                            x: pos.x,
-                            y: pos.y + 1,
-                        }),
-                        Some(Vec2 {
-                            x: pos.x + 1,
-                            y: pos.y + 1,
-                        }),
-                    ]
-                } else {
-                    [Some(pos), None, None, None]
-                };
-                for pos in solution_candidates
-                    .into_iter()
-                    .flatten()
-                {
-                    if pos.x < 0 || pos.y < 0 || pos.x > dimension || pos.y > dimension {
-                        // Intersection is outside of the map
-                        continue;
-                    }
-                    if let Some(solution) = is_solution(&pos, sensors.iter(), dimension) {
-                        return solution;
-                    }
-                }
-            }
-        }
-    }
+/// Uncovered position in the search area at, or next to, the intersection of two segments.
+pub(crate) fn solve_at_intersection<T: Coord>(
+    pos_slope_segment: &Segment<T>,
+    neg_slope_segment: &Segment<T>,
+    sensors: &[Sensor<T>],
+    dimension: T,
+    stats: &mut Stats,
+) -> Option<Vec2<T>> {
+    stats.segment_intersections += 1;
+    let (pos, is_in_center) = segment_intersection(pos_slope_segment, neg_slope_segment)?;
+    let solution_candidates = if is_in_center {
+        [
+            Some(pos),
+            Some(Vec2 {
+                x: pos.x + T::ONE,
+                y: pos.y,
+            }),
+            Some(Vec2 {
+                x: pos.x,
+                y: pos.y + T::ONE,
+            }),
+            Some(Vec2 {
+                x: pos.x + T::ONE,
+                y: pos.y + T::ONE,
+            }),
+        ]
+    } else {
+        [Some(pos), None, None, None]
+    };
+    solution_candidates.into_iter().flatten().find(|pos| {
+        // Skip intersections outside of the map
+        (T::ZERO..=dimension).contains(&pos.x)
+            && (T::ZERO..=dimension).contains(&pos.y)
+            && is_solution(pos, sensors.iter(), stats)
+    })
+}
 
-    panic!("no solution found!");
+/// Whether `point` is outside the range of every sensor.
+fn is_solution<'a, T: Coord + 'a>(
+    point: &Vec2<T>,
+    mut sensors: impl Iterator<Item = &'a Sensor<T>>,
+    stats: &mut Stats,
+) -> bool {
+    stats.cells_probed += 1;
+    sensors.all(|sensor| {
+        stats.sensors_checked += 1;
+        point.manhattan_distance(sensor.pos) > sensor.range
+    })
+}
 
-    fn is_solution<'a>(
-        point: &Vec2,
-        sensors: impl Iterator<Item = &'a Sensor>,
-        dimension: i32,
-    ) -> Option<(Vec2, i64)> {
-        for sensor in sensors {
-            if point.manhattan_distance(&sensor.pos) <= sensor.range {
-                return None;
-            }
-        }
-        let answer = point.x as i64 * dimension as i64 + point.y as i64;
-        Some((point.clone(), answer))
-    }
+/// Positions on the edges of the search area which are just outside the sensor's range.
+fn edge_intersections<T: Coord>(
+    sensor: &Sensor<T>,
+    dimension: T,
+) -> impl Iterator<Item = Vec2<T>> + '_ {
+    let reach = sensor.range + T::ONE;
+    [T::ZERO, dimension].into_iter().flat_map(move |edge| {
+        // Distance along the edge from the point on the edge closest to the sensor
+        let along_x_edge = reach - (edge - sensor.pos.y).abs();
+        let along_y_edge = reach - (edge - sensor.pos.x).abs();
+        let x_edge = [-along_x_edge, along_x_edge].map(|dx| Vec2 {
+            x: sensor.pos.x + dx,
+            y: edge,
+        });
+        let y_edge = [-along_y_edge, along_y_edge].map(|dy| Vec2 {
+            x: edge,
+            y: sensor.pos.y + dy,
+        });
+        let x_edge = x_edge.into_iter().filter(move |_| along_x_edge >= T::ZERO);
+        let y_edge = y_edge.into_iter().filter(move |_| along_y_edge >= T::ZERO);
+        x_edge.chain(y_edge).filter(move |pos| {
+            (T::ZERO..=dimension).contains(&pos.x) && (T::ZERO..=dimension).contains(&pos.y)
+        })
+    })
 }
 
-fn create_positive_slope_segments(sensor: &Sensor) -> [Segment; 2] {
+fn create_positive_slope_segments<T: Coord>(sensor: &Sensor<T>) -> [Segment<T>; 2] {
     [
         Segment {
-            y_intercept: sensor.pos.y - sensor.pos.x - (sensor.range + 1),
+            y_intercept: sensor.pos.y - sensor.pos.x - (sensor.range + T::ONE),
             bounds: Aabb {
-                x: (sensor.pos.x..sensor.pos.x + (sensor.range + 1) + 1),
-                y: (sensor.pos.y - (sensor.range + 1)..sensor.pos.y + 1),
+                x: (sensor.pos.x..sensor.pos.x + (sensor.range + T::ONE) + T::ONE),
+                y: (sensor.pos.y - (sensor.range + T::ONE)..sensor.pos.y + T::ONE),
             },
         },
         Segment {
-            y_intercept: sensor.pos.y - sensor.pos.x + (sensor.range + 1),
+            y_intercept: sensor.pos.y - sensor.pos.x + (sensor.range + T::ONE),
             bounds: Aabb {
-                x: (sensor.pos.x - (sensor.range + 1)..sensor.pos.x + 1),
-                y: (sensor.pos.y..sensor.pos.y + 1 + (sensor.range + 1)),
+                x: (sensor.pos.x - (sensor.range + T::ONE)..sensor.pos.x + T::ONE),
+                y: (sensor.pos.y..sensor.pos.y + T::ONE + (sensor.range + T::ONE)),
             },
         },
     ]
 }
 
-fn create_negative_slope_segments(sensor: &Sensor) -> [Segment; 2] {
+fn create_negative_slope_segments<T: Coord>(sensor: &Sensor<T>) -> [Segment<T>; 2] {
     [
         Segment {
-            y_intercept: sensor.pos.y + sensor.pos.x - (sensor.range + 1),
+            y_intercept: sensor.pos.y + sensor.pos.x - (sensor.range + T::ONE),
             bounds: Aabb {
-                x: (sensor.pos.x - (sensor.range + 1)..sensor.pos.x + 1),
-                y: (sensor.pos.y - (sensor.range + 1)..sensor.pos.y + 1),
+                x: (sensor.pos.x - (sensor.range + T::ONE)..sensor.pos.x + T::ONE),
+                y: (sensor.pos.y - (sensor.range + T::ONE)..sensor.pos.y + T::ONE),
             },
         },
         Segment {
-            y_intercept: sensor.pos.y + sensor.pos.x + (sensor.range + 1),
+            y_intercept: sensor.pos.y + sensor.pos.x + (sensor.range + T::ONE),
             bounds: Aabb {
-                x: (sensor.pos.x..sensor.pos.x + (sensor.range + 1) + 1),
-                y: (sensor.pos.y..sensor.pos.y + 1 + (sensor.range + 1)),
+                x: (sensor.pos.x..sensor.pos.x + (sensor.range + T::ONE) + T::ONE),
+                y: (sensor.pos.y..sensor.pos.y + T::ONE + (sensor.range + T::ONE)),
             },
         },
     ]
@@ -121,11 +217,11 @@ fn create_negative_slope_segments(sensor: &Sensor) -> [Segment; 2] {
 
 /// Simplified line intersection algorithm for lines with slope 1 and -1.
 /// Returns Option<(intersection_point, is_intersection_in_center)>
-fn segment_intersection(
-    pos_slope_segment: &Segment,
-    neg_slope_segment: &Segment,
-) -> Option<(Vec2, bool)> {
-    let y = (pos_slope_segment.y_intercept + neg_slope_segment.y_intercept) / 2;
+fn segment_intersection<T: Coord>(
+    pos_slope_segment: &Segment<T>,
+    neg_slope_segment: &Segment<T>,
+) -> Option<(Vec2<T>, bool)> {
+    let y = (pos_slope_segment.y_intercept + neg_slope_segment.y_intercept) / T::TWO;
     let result = Vec2 {
         x: -pos_slope_segment.y_intercept + y,
         y,
@@ -133,7 +229,7 @@ fn segment_intersection(
     if pos_slope_segment.bounds.contains(&result) && neg_slope_segment.bounds.contains(&result) {
         Some((
             result,
-            pos_slope_segment.y_intercept % 2 != neg_slope_segment.y_intercept % 2,
+            pos_slope_segment.y_intercept % T::TWO != neg_slope_segment.y_intercept % T::TWO,
         ))
     } else {
         None
@@ -141,9 +237,9 @@ fn segment_intersection(
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Segment {
-    y_intercept: i32,
-    bounds: Aabb,
+pub(crate) struct Segment<T> {
+    y_intercept: T,
+    bounds: Aabb<T>,
 }
 
 #[cfg(test)]
@@ -151,42 +247,11 @@ mod tests {
     use crate::{
         border_intersection::{
             create_negative_slope_segments, create_positive_slope_segments, segment_intersection,
-            solve, Segment,
+            Segment,
         },
-        test_case::{self, TestCase},
         Aabb, Sensor, Vec2,
     };
 
-    fn test_case<T>(test_case: T)
-    where
-        TestCase: From<T>,
-        T: std::fmt::Debug,
-    {
-        let mut test_case = TestCase::from(test_case);
-        // Test each case in 4 rotations
-        for _i in 0..4 {
-            let mut sensors = test_case.sensors.to_vec();
-            let result = solve(&mut sensors, test_case.dimension);
-            assert_eq!(&result.0, &test_case.expected_pos);
-            assert_eq!(result.1, test_case.expected_answer());
-            test_case = test_case.rotated();
-        }
-    }
-
-    #[test]
-    fn const_test_cases() {
-        for case in test_case::CONST_TEST_CASES {
-            test_case(case);
-        }
-    }
-
-    #[test]
-    fn file_test_cases() {
-        for case in test_case::FILE_TEST_CASES {
-            test_case(case);
-        }
-    }
-
     #[test]
     fn create_segments() {
         assert_eq!(
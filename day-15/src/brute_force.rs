@@ -1,64 +1,109 @@
-use crate::{Sensor, Vec2};
+use crate::{
+    metric::{Manhattan, Metric},
+    solver::{Part2Solver, Stats},
+    Coord, Sensor, Vec2,
+};
 
-pub fn solve(sensors: &mut [Sensor], dimension: i32) -> (Vec2, i64) {
-    const MAX_ITERS: i32 = 10_000_000;
+/// Checks every position in the search area, up to a limit of `MAX_ITERS` positions.
+pub struct BruteForce;
+
+/// Number of positions checked before giving up on the rest of the search area.
+const MAX_ITERS: i128 = 10_000_000;
+
+impl<T: Coord> Part2Solver<T> for BruteForce {
+    fn name(&self) -> &'static str {
+        "Brute Force"
+    }
+
+    fn solve_with_stats(&self, sensors: &[Sensor<T>], search_size: T) -> (Option<Vec2<T>>, Stats) {
+        let mut stats = Stats::default();
+        let pos = scan(Manhattan, sensors, search_size, 1, &mut stats).pop();
+        (pos, stats)
+    }
+
+    fn uncovered(&self, sensors: &[Sensor<T>], search_size: T, limit: usize) -> Vec<Vec2<T>> {
+        solve(sensors, search_size, limit)
+    }
+
+    fn is_practical(&self, search_size: T) -> bool {
+        (search_size.to_i128() + 1).pow(2) <= MAX_ITERS
+    }
+}
+
+/// Uncovered positions in the search area, up to `limit` of them, ordered by x and then y. Only
+/// the first `MAX_ITERS` positions in that order are checked, so in larger search areas any
+/// uncovered positions after those are missed.
+pub fn solve<T: Coord>(sensors: &[Sensor<T>], dimension: T, limit: usize) -> Vec<Vec2<T>> {
+    solve_in(Manhattan, sensors, dimension, limit)
+}
+
+/// Uncovered positions in the search area, measuring the sensors' ranges with `metric`, up to
+/// `limit` of them, ordered by x and then y. Only the first `MAX_ITERS` positions are checked,
+/// as in [`solve`].
+pub fn solve_in<T: Coord>(
+    metric: impl Metric,
+    sensors: &[Sensor<T>],
+    dimension: T,
+    limit: usize,
+) -> Vec<Vec2<T>> {
+    scan(metric, sensors, dimension, limit, &mut Stats::default())
+}
+
+fn scan<T: Coord>(
+    metric: impl Metric,
+    sensors: &[Sensor<T>],
+    dimension: T,
+    limit: usize,
+    stats: &mut Stats,
+) -> Vec<Vec2<T>> {
+    let mut uncovered = Vec::new();
+    if limit == 0 {
+        return uncovered;
+    }
     let mut count = 0;
-    for x in 0..=dimension {
-        'iter_points: for y in 0..=dimension {
+    let mut x = T::ZERO;
+    while x <= dimension {
+        let mut y = T::ZERO;
+        while y <= dimension {
             if count >= MAX_ITERS {
-                panic!("Too many iterations: {count}");
+                return uncovered;
             }
             count += 1;
-            for sensor in sensors.iter() {
-                if sensor.pos.manhattan_distance(&Vec2 { x, y }) <= sensor.range {
-                    continue 'iter_points;
+            stats.cells_probed += 1;
+            let pos = Vec2 { x, y };
+            if !sensors.iter().any(|sensor| {
+                stats.sensors_checked += 1;
+                sensor.covers_in(metric, pos)
+            }) {
+                uncovered.push(pos);
+                if uncovered.len() == limit {
+                    return uncovered;
                 }
             }
-            let answer = x as i64 * dimension as i64 + y as i64;
-            return (Vec2 { x, y }, answer);
+            y += T::ONE;
         }
+        x += T::ONE;
     }
-    unreachable!("No solution found")
+    uncovered
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        brute_force::solve,
-        test_case::{self, TestCase},
-    };
-
-    fn test_case<T>(test_case: T)
-    where
-        TestCase: From<T>,
-    {
-        let mut test_case = TestCase::from(test_case);
-        // Solve each test case in 4 different rotations.
-        for r in 0..4 {
-            println!("case: {:?}, rotation: {:?}", test_case.name, r);
-            let mut sensors = test_case.sensors.to_vec();
-            let result = solve(&mut sensors, test_case.dimension);
-            assert_eq!(&result.0, &test_case.expected_pos);
-            assert_eq!(result.1, test_case.expected_answer());
-            test_case = test_case.rotated();
-        }
-    }
+    use super::{solve, BruteForce, MAX_ITERS};
+    use crate::{solver::Part2Solver, Sensor, Vec2};
 
     #[test]
-    fn const_test_cases() {
-        for case in test_case::CONST_TEST_CASES {
-            test_case(case);
-        }
-    }
-
-    #[test]
-    fn file_test_cases() {
-        for case in test_case::FILE_TEST_CASES {
-            if case.name == "AOC Actual" {
-                // Skip AOC Actual case because it takes 3.5 days to run...
-                continue;
-            }
-            test_case(case);
-        }
+    fn search_area_too_large() {
+        // Covers everything but the last position, which is beyond the limit
+        let sensors = [Sensor {
+            id: 0,
+            pos: Vec2 { x: 0, y: 0 },
+            range: 9_999,
+        }];
+        assert!(!BruteForce.is_practical(5_000));
+        assert_eq!(solve(&sensors, 5_000, 1), []);
+        assert_eq!(BruteForce.solve(&sensors, 5_000), None);
+        assert_eq!(solve(&[], 5_000, 2).len(), 2);
+        assert!(BruteForce.is_practical((MAX_ITERS as f64).sqrt() as i32 - 1));
     }
 }
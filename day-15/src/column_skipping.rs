@@ -1,61 +1,100 @@
-use crate::{Sensor, Vec2};
+use std::ops::Range;
 
-pub fn solve(sensors: &mut Vec<Sensor>, search_area_size: i32) -> (Vec2, i64) {
-    let mut pos = Vec2 { x: 0, y: 0 };
-    'outer: loop {
+use crate::{
+    metric::{Manhattan, Metric},
+    solver::{Part2Solver, Stats},
+    Coord, Sensor, Vec2,
+};
+
+/// Scans the search area row by row, skipping past the rest of each sensor's range in the row.
+pub struct ColumnSkipping;
+
+/// Number of rows above which scanning the search area takes too long.
+const MAX_ROWS: i128 = 100_000_000;
+
+impl<T: Coord> Part2Solver<T> for ColumnSkipping {
+    fn name(&self) -> &'static str {
+        "Column Skipping"
+    }
+
+    fn solve_with_stats(&self, sensors: &[Sensor<T>], search_size: T) -> (Option<Vec2<T>>, Stats) {
+        let mut stats = Stats::default();
+        let rows = T::ZERO..search_size + T::ONE;
+        let pos = scan_rows(Manhattan, sensors, search_size, rows, 1, &mut stats).pop();
+        (pos, stats)
+    }
+
+    fn uncovered(&self, sensors: &[Sensor<T>], search_size: T, limit: usize) -> Vec<Vec2<T>> {
+        solve(sensors, search_size, limit)
+    }
+
+    fn is_practical(&self, search_size: T) -> bool {
+        search_size.to_i128() < MAX_ROWS
+    }
+}
+
+/// Uncovered positions in the search area, up to `limit` of them, ordered by y and then x.
+pub fn solve<T: Coord>(sensors: &[Sensor<T>], search_area_size: T, limit: usize) -> Vec<Vec2<T>> {
+    solve_in(Manhattan, sensors, search_area_size, limit)
+}
+
+/// Uncovered positions in the search area, measuring the sensors' ranges with `metric`, up to
+/// `limit` of them, ordered by y and then x.
+pub fn solve_in<T: Coord>(
+    metric: impl Metric,
+    sensors: &[Sensor<T>],
+    search_area_size: T,
+    limit: usize,
+) -> Vec<Vec2<T>> {
+    scan_rows(
+        metric,
+        sensors,
+        search_area_size,
+        T::ZERO..search_area_size + T::ONE,
+        limit,
+        &mut Stats::default(),
+    )
+}
+
+/// Uncovered positions in the given rows of the search area, up to `limit` of them, ordered by y
+/// and then x.
+pub(crate) fn scan_rows<T: Coord>(
+    metric: impl Metric,
+    sensors: &[Sensor<T>],
+    search_area_size: T,
+    rows: Range<T>,
+    limit: usize,
+    stats: &mut Stats,
+) -> Vec<Vec2<T>> {
+    let mut uncovered = Vec::new();
+    let mut pos = Vec2 {
+        x: T::ZERO,
+        y: rows.start,
+    };
+    'outer: while pos.y < rows.end && uncovered.len() < limit {
+        stats.cells_probed += 1;
         for sensor in sensors.iter() {
-            if sensor.pos.manhattan_distance(&pos) <= sensor.range {
+            stats.sensors_checked += 1;
+            if sensor.covers_in(metric, pos) {
                 // Advance x past the sensors range
-                pos.x = sensor.pos.x + sensor.range - (sensor.pos.y - pos.y).abs() + 1;
+                let reach = metric
+                    .reach(sensor.range, sensor.pos.y - pos.y)
+                    .expect("the sensor covers a position in the row");
+                pos.x = sensor.pos.x + reach + T::ONE;
                 if pos.x > search_area_size {
                     // Wrap around
-                    pos.x = 0;
-                    pos.y += 1;
-                }
-                if pos.y > search_area_size {
-                    panic!("No solution found");
+                    pos.x = T::ZERO;
+                    pos.y += T::ONE;
                 }
                 continue 'outer;
             }
         }
-        let result = pos.x as i64 * search_area_size as i64 + pos.y as i64;
-        return (pos, result);
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{
-        column_skipping::solve,
-        test_case::{self, TestCase},
-    };
-
-    fn test_case<T>(test_case: T)
-    where
-        TestCase: From<T>,
-    {
-        let mut test_case = TestCase::from(test_case);
-        // Solve each test case in 4 different rotations.
-        for _ in 0..4 {
-            let mut sensors = test_case.sensors.to_vec();
-            let result = solve(&mut sensors, test_case.dimension);
-            assert_eq!(&result.0, &test_case.expected_pos);
-            assert_eq!(result.1, test_case.expected_answer());
-            test_case = test_case.rotated();
-        }
-    }
-
-    #[test]
-    fn const_test_cases() {
-        for case in test_case::CONST_TEST_CASES {
-            test_case(case);
-        }
-    }
-
-    #[test]
-    fn file_test_cases() {
-        for case in test_case::FILE_TEST_CASES {
-            test_case(case);
+        uncovered.push(pos);
+        pos.x += T::ONE;
+        if pos.x > search_area_size {
+            pos.x = T::ZERO;
+            pos.y += T::ONE;
         }
     }
+    uncovered
 }
@@ -0,0 +1,334 @@
+//! Exact number of cells covered by the union of the sensors' ranges, within a row, a column or
+//! any rectangle.
+//!
+//! Rows and columns are handled by merging the interval covered by each sensor. Rectangles are
+//! handled in diagonal space (see [`crate::range_exclusion`]), where each sensor's range is an
+//! [`Aabb`]: the y_diag coordinates covered only change at the edges of the sensors' x_diag
+//! ranges, so the covered y_diag ranges are merged once per band of x_diag coordinates between
+//! those edges, rather than once per row. The rectangle's bounds on y_diag are piecewise linear
+//! in x_diag, so the cells in each band are counted in closed form, and the time taken depends
+//! only on the number of sensors, not on the size of the rectangle.
+
+use std::ops::Range;
+
+use aoc_common::interval::{RangeOps, RangeSet};
+
+use crate::{
+    range_exclusion::{aabb_from_sensor, rectangular_to_diagonal},
+    Aabb, Sensor, Vec2,
+};
+
+/// Ranges of x coordinates covered by at least one sensor in row `y`.
+pub fn row_coverage(sensors: &[Sensor], y: i32) -> RangeSet {
+    let mut covered = RangeSet::new();
+    for sensor in sensors {
+        let half_width = sensor.range - (y - sensor.pos.y).abs();
+        covered.add_range(&(sensor.pos.x - half_width..sensor.pos.x + half_width + 1));
+    }
+    covered
+}
+
+/// Ranges of y coordinates covered by at least one sensor in column `x`.
+pub fn column_coverage(sensors: &[Sensor], x: i32) -> RangeSet {
+    let mut covered = RangeSet::new();
+    for sensor in sensors {
+        let half_height = sensor.range - (x - sensor.pos.x).abs();
+        covered.add_range(&(sensor.pos.y - half_height..sensor.pos.y + half_height + 1));
+    }
+    covered
+}
+
+/// Number of cells in row `y`, with an x coordinate in `x`, which are covered by a sensor.
+pub fn covered_in_row(sensors: &[Sensor], y: i32, x: Range<i32>) -> u64 {
+    count_within(&row_coverage(sensors, y), &x)
+}
+
+/// Number of cells in column `x`, with a y coordinate in `y`, which are covered by a sensor.
+pub fn covered_in_column(sensors: &[Sensor], x: i32, y: Range<i32>) -> u64 {
+    count_within(&column_coverage(sensors, x), &y)
+}
+
+/// Number of cells in `area` which are covered by a sensor.
+pub fn covered_in_area(sensors: &[Sensor], area: &Aabb) -> u64 {
+    if area.x.is_empty() || area.y.is_empty() {
+        return 0;
+    }
+    // Diagonal coordinates are sums and differences of two coordinates, and the bounds below
+    // double them, so they are computed in `i64` to avoid overflowing near the limits of `i32`.
+    let (min, max) = (
+        Vec2::<i64> {
+            x: area.x.start.into(),
+            y: area.y.start.into(),
+        },
+        Vec2::<i64> {
+            x: i64::from(area.x.end) - 1,
+            y: i64::from(area.y.end) - 1,
+        },
+    );
+    let sensors_diag: Vec<Aabb<i64>> = sensors
+        .iter()
+        .map(|s| aabb_from_sensor(&s.cast(), 0))
+        .collect();
+
+    // The area is a diamond in diagonal space, spanning the x_diag coordinates from its bottom
+    // left corner to its top right corner.
+    let x_diag_start = rectangular_to_diagonal(&Vec2 { x: min.x, y: max.y }, 0).x;
+    let x_diag_end = rectangular_to_diagonal(&Vec2 { x: max.x, y: min.y }, 0).x + 1;
+    let mut band_starts: Vec<i64> = sensors_diag
+        .iter()
+        .flat_map(|s| [s.x.start, s.x.end])
+        .filter(|x_diag| (x_diag_start..x_diag_end).contains(x_diag))
+        .chain([x_diag_start])
+        .collect();
+    band_starts.sort_unstable();
+    band_starts.dedup();
+
+    let mut covered = 0;
+    for (idx, &band_start) in band_starts.iter().enumerate() {
+        let band_end = band_starts.get(idx + 1).copied().unwrap_or(x_diag_end);
+        let mut covered_y_diag = RangeSet::new();
+        for sensor in sensors_diag.iter().filter(|s| s.x.contains(&band_start)) {
+            covered_y_diag.add_range(&sensor.y);
+        }
+        for range in covered_y_diag.ranges() {
+            covered += count_in_band(band_start..band_end, range, min, max);
+        }
+    }
+    covered
+}
+
+/// Number of cells from `min` to `max` inclusive, with an x_diag coordinate in `x_diags` and a
+/// y_diag coordinate in `y_diags`.
+fn count_in_band(x_diags: Range<i64>, y_diags: &Range<i64>, min: Vec2<i64>, max: Vec2<i64>) -> u64 {
+    // Bounds of the area's diamond, from x >= min.x, y >= min.y, x <= max.x and y <= max.y,
+    // where x = (x_diag + y_diag) / 2 and y = (y_diag - x_diag) / 2.
+    let lower = [
+        Bound::new(y_diags.start, 0),
+        Bound::new(2 * min.x, -1),
+        Bound::new(2 * min.y, 1),
+    ];
+    let upper = [
+        Bound::new(y_diags.end, 0),
+        Bound::new(2 * max.x + 1, -1),
+        Bound::new(2 * max.y + 1, 1),
+    ];
+
+    // Between consecutive breakpoints, no two bounds cross, so the same bounds are the tightest
+    // throughout, and the y_diag range between them is either empty throughout or never.
+    let (start, end) = (x_diags.start, x_diags.end);
+    let bounds = lower.iter().chain(&upper);
+    let mut breakpoints: Vec<i64> = bounds
+        .clone()
+        .flat_map(|a| bounds.clone().filter_map(|b| a.crossing(b)))
+        .flat_map(|x_diag| [x_diag, x_diag + 1])
+        .filter(|x_diag| (start..end).contains(x_diag))
+        .chain([start, end])
+        .collect();
+    breakpoints.sort_unstable();
+    breakpoints.dedup();
+
+    let mut covered = 0;
+    for piece in breakpoints.windows(2) {
+        let (start, end) = (piece[0], piece[1]);
+        let low = lower.iter().max_by_key(|bound| bound.at(start)).unwrap();
+        let high = upper.iter().min_by_key(|bound| bound.at(start)).unwrap();
+        if high.at(start) > low.at(start) {
+            covered += high.parity_sum(start..end) - low.parity_sum(start..end);
+        }
+    }
+    covered as u64
+}
+
+/// Bound on y_diag coordinates, `offset + slope * x_diag`, for a slope of -1, 0 or 1.
+#[derive(Debug, Clone, Copy)]
+struct Bound {
+    offset: i64,
+    slope: i64,
+}
+
+impl Bound {
+    fn new(offset: i64, slope: i64) -> Self {
+        Bound { offset, slope }
+    }
+
+    fn at(self, x_diag: i64) -> i64 {
+        self.offset + self.slope * x_diag
+    }
+
+    /// The x_diag coordinate at or just before where the bounds cross, or `None` if they are
+    /// parallel.
+    fn crossing(&self, other: &Bound) -> Option<i64> {
+        let (num, den) = (self.offset - other.offset, other.slope - self.slope);
+        match den.signum() {
+            0 => None,
+            sign => Some((sign * num).div_euclid(sign * den)),
+        }
+    }
+
+    /// Sum over `x_diags` of the number of values below the bound with the same parity as
+    /// x_diag, relative to an arbitrary origin. Only diagonal coordinates where x_diag and y_diag
+    /// have the same parity correspond to a cell.
+    fn parity_sum(self, x_diags: Range<i64>) -> i64 {
+        // Each term is (offset + 1 + (slope - 1) * x_diag).div_euclid(2)
+        let offset = self.offset + 1;
+        let len = x_diags.end - x_diags.start;
+        if self.slope == 0 {
+            // Sum of k.div_euclid(2) for k from offset + 1 - end to offset - start inclusive
+            floor_half_sum(offset - x_diags.start + 1) - floor_half_sum(offset - x_diags.end + 1)
+        } else {
+            // Sums of x_diag over the range are exact, as one of len and start + end - 1 is even
+            let x_diag_sum = (x_diags.start + x_diags.end - 1) * len / 2;
+            offset.div_euclid(2) * len + (self.slope - 1) / 2 * x_diag_sum
+        }
+    }
+}
+
+/// Sum of `k.div_euclid(2)` for `k` from 0 to `n` exclusive, extended to negative `n` so that
+/// the difference between `n + 1` and `n` is always `n.div_euclid(2)`.
+fn floor_half_sum(n: i64) -> i64 {
+    n.div_euclid(2) * (n - 1).div_euclid(2)
+}
+
+/// Number of values in `ranges` which are also in `within`.
+fn count_within(ranges: &RangeSet, within: &Range<i32>) -> u64 {
+    ranges
+        .ranges()
+        .iter()
+        .map(|range| range.intersection(within).size().max(0) as u64)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{covered_in_area, covered_in_column, covered_in_row, row_coverage};
+    use crate::{
+        test_case::{self, TestCase},
+        Aabb, Sensor, Vec2,
+    };
+
+    fn brute_force(sensors: &[Sensor], area: &Aabb) -> u64 {
+        let mut covered = 0;
+        for x in area.x.clone() {
+            for y in area.y.clone() {
+                if sensors
+                    .iter()
+                    .any(|s| s.pos.manhattan_distance(Vec2 { x, y }) <= s.range)
+                {
+                    covered += 1;
+                }
+            }
+        }
+        covered
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn example_row() {
+        let example = TestCase::from(&test_case::AOC_EXAMPLE);
+        // 26 cells where a beacon can't be, plus the beacon at x=2
+        assert_eq!(covered_in_row(&example.sensors, 10, -100..100), 27);
+        assert_eq!(row_coverage(&example.sensors, 10).ranges(), [-2..25]);
+        assert_eq!(covered_in_row(&example.sensors, 10, 0..21), 21);
+        assert_eq!(covered_in_row(&example.sensors, 11, 0..21), 20);
+        assert_eq!(covered_in_column(&example.sensors, 14, 0..21), 20);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn areas_match_brute_force() {
+        let cases = test_case::get_test_cases().filter(|case| case.dimension <= 20);
+        for case in cases {
+            let size = case.dimension;
+            let areas = [
+                Aabb {
+                    x: 0..size + 1,
+                    y: 0..size + 1,
+                },
+                Aabb {
+                    x: -3..size + 4,
+                    y: -2..size + 1,
+                },
+                Aabb {
+                    x: 1..size,
+                    y: -5..size / 2,
+                },
+                Aabb { x: 2..3, y: 1..2 },
+                Aabb { x: 3..1, y: 0..5 },
+            ];
+            for area in areas {
+                assert_eq!(
+                    covered_in_area(&case.sensors, &area),
+                    brute_force(&case.sensors, &area),
+                    "case {:?}, area {area:?}",
+                    case.name
+                );
+            }
+            for y in -2..size + 2 {
+                let row = Aabb {
+                    x: -1..size + 2,
+                    y: y..y + 1,
+                };
+                assert_eq!(
+                    covered_in_row(&case.sensors, y, row.x.clone()),
+                    brute_force(&case.sensors, &row)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn coordinates_near_limits() {
+        for (x, y) in [
+            (1_500_000_000, 2_000_000_000),
+            (-2_100_000_000, 1_600_000_000),
+        ] {
+            let sensors = [
+                Sensor {
+                    id: 0,
+                    pos: Vec2 { x, y },
+                    range: 3,
+                },
+                Sensor {
+                    id: 1,
+                    pos: Vec2 { x: x + 5, y: y - 2 },
+                    range: 2,
+                },
+            ];
+            let area = Aabb {
+                x: x - 4..x + 9,
+                y: y - 6..y + 5,
+            };
+            assert_eq!(
+                covered_in_area(&sensors, &area),
+                brute_force(&sensors, &area)
+            );
+        }
+    }
+
+    #[test]
+    fn actual_search_area() {
+        let actual = TestCase::from(&test_case::AOC_ACTUAL);
+        let size = actual.dimension;
+        let area = Aabb {
+            x: 0..size + 1,
+            y: 0..size + 1,
+        };
+        // Everything but the distress beacon is covered
+        assert_eq!(
+            covered_in_area(&actual.sensors, &area),
+            (size as u64 + 1).pow(2) - 1
+        );
+
+        // A wide area, where each band spans many x_diag coordinates
+        let area = Aabb {
+            x: 1_234_567..3_456_789,
+            y: 2_000_000..2_001_500,
+        };
+        let rows: u64 = area
+            .y
+            .clone()
+            .map(|y| covered_in_row(&actual.sensors, y, area.x.clone()))
+            .sum();
+        assert_eq!(covered_in_area(&actual.sensors, &area), rows);
+    }
+}
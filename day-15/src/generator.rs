@@ -0,0 +1,346 @@
+//! Random sensor layouts with exactly one uncovered position in the search area, for stress
+//! testing and benchmarking the part 2 solvers on inputs of any size.
+//!
+//! A layout starts with a sensor diagonally away from the uncovered position, the hole, in each
+//! direction, each with a range reaching just short of it. Together they cover every position
+//! next to the hole. Any other uncovered position is then found with
+//! [`border_intersection::solve`] and covered by a new sensor whose range doesn't reach the hole,
+//! until only the hole is left. Finally, sensors which don't reach the hole are added at random
+//! to make up the requested number.
+//!
+//! As in the puzzle, each sensor's beacon is its closest: no sensor is placed where another
+//! sensor's beacon would be closer to it than its own, or where its beacon would be closer to
+//! another sensor than that sensor's own.
+
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{border_intersection, test_case::TestCase, Sensor, Vec2};
+
+/// Number of holes tried before giving up on covering the search area with the requested
+/// number of sensors, and of positions tried for each sensor before trying another hole.
+const MAX_ATTEMPTS: usize = 100;
+
+/// The sensors added to make up the requested number have ranges of at most the search size
+/// divided by this, or 1 in small search areas. Larger ranges overlap so much that range
+/// exclusion, which considers chains of overlapping sensors, takes too long.
+const MAX_RANGE_DIVISOR: i32 = 8;
+
+/// Layouts with more sensors than this have smaller ranges for the sensors added to make up the
+/// number, shrinking with the square root of the number of sensors, so that there is room for all
+/// of their beacons.
+const CROWDED_SENSOR_COUNT: usize = 50;
+
+/// Largest search size for which sensor ranges fit in an `i32`.
+const MAX_SEARCH_SIZE: i32 = i32::MAX / 4;
+
+/// Sensor layout with exactly one uncovered position in the search area.
+#[derive(Debug, Clone)]
+pub struct Layout {
+    pub sensors: Vec<Sensor>,
+    /// Position of the closest beacon to each sensor, in the same order as `sensors`.
+    pub beacons: Vec<Vec2>,
+    pub search_size: i32,
+    /// The only position in the search area which isn't covered by a sensor.
+    pub hole: Vec2,
+}
+
+impl Layout {
+    /// The layout in the puzzle input format read by [`crate::parse`], with a
+    /// [`Header`](crate::Header) giving the search size and the hole, so it can be solved by
+    /// the binaries or added to the test cases.
+    pub fn to_input(&self) -> String {
+        let mut input = String::new();
+        writeln!(
+            input,
+            "Search area size {}: distress beacon is at x={}, y={}",
+            self.search_size, self.hole.x, self.hole.y
+        )
+        .unwrap();
+        for (sensor, beacon) in self.sensors.iter().zip(&self.beacons) {
+            writeln!(
+                input,
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sensor.pos.x, sensor.pos.y, beacon.x, beacon.y
+            )
+            .unwrap();
+        }
+        input
+    }
+}
+
+impl From<&Layout> for TestCase {
+    fn from(layout: &Layout) -> Self {
+        Self {
+            name: "Generated".to_string(),
+            sensors: layout.sensors.clone(),
+            dimension: layout.search_size,
+            expected_pos: layout.hole,
+        }
+    }
+}
+
+/// Generates a layout of `sensor_count` sensors for a search area of the given size. The same
+/// seed always gives the same layout.
+///
+/// Returns `None` if the search area couldn't be covered with so few sensors. At least 4 are
+/// needed, and larger search areas usually need a few more.
+///
+/// Sensors are placed up to `search_size` outside the search area, so their ranges can be up to
+/// 4 times the search size. The solvers work in terms of sums of coordinates and ranges, so they
+/// need `i64` coordinates (see [`Sensor::cast`]) for search sizes above about 250,000,000.
+pub fn generate(search_size: i32, sensor_count: usize, seed: u64) -> Option<Layout> {
+    assert!(
+        (0..=MAX_SEARCH_SIZE).contains(&search_size),
+        "the search size must be between 0 and {MAX_SEARCH_SIZE}"
+    );
+    // Smaller sensors leave room for more beacons which aren't in range of the other sensors
+    let crowding = ((sensor_count / CROWDED_SENSOR_COUNT) as f64)
+        .sqrt()
+        .max(1.0) as i32;
+    let max_padding_range = (search_size / MAX_RANGE_DIVISOR / crowding).max(1);
+    let mut rng = StdRng::seed_from_u64(seed);
+    'holes: for _ in 0..MAX_ATTEMPTS {
+        let hole = Vec2 {
+            x: rng.gen_range(0..=search_size),
+            y: rng.gen_range(0..=search_size),
+        };
+        let Some(mut sensors) = cover_all_but(hole, search_size, sensor_count, &mut rng) else {
+            continue;
+        };
+        while sensors.len() < sensor_count {
+            let added = (0..MAX_ATTEMPTS).find_map(|_| {
+                let pos = random_pos(search_size, &mut rng);
+                let range = rng.gen_range(1..=max_padding_range);
+                let sensor = PlacedSensor::new(pos, beacon_for(pos, range, &mut rng));
+                (range < pos.manhattan_distance(hole) && sensor.fits_with(&sensors))
+                    .then_some(sensor)
+            });
+            match added {
+                Some(sensor) => sensors.push(sensor),
+                None => continue 'holes,
+            }
+        }
+        sensors.shuffle(&mut rng);
+
+        let beacons = sensors.iter().map(|sensor| sensor.beacon).collect();
+        let sensors = sensors
+            .into_iter()
+            .enumerate()
+            .map(|(idx, sensor)| Sensor {
+                // Sensors parsed from the input are identified by their line number, after the
+                // header
+                id: idx + 2,
+                pos: sensor.pos,
+                range: sensor.range,
+            })
+            .collect();
+        return Some(Layout {
+            sensors,
+            beacons,
+            search_size,
+            hole,
+        });
+    }
+    None
+}
+
+/// Sensor being placed, whose range reaches its closest beacon.
+#[derive(Debug, Clone, Copy)]
+struct PlacedSensor {
+    pos: Vec2,
+    range: i32,
+    beacon: Vec2,
+}
+
+impl PlacedSensor {
+    fn new(pos: Vec2, beacon: Vec2) -> Self {
+        PlacedSensor {
+            pos,
+            range: pos.manhattan_distance(beacon),
+            beacon,
+        }
+    }
+
+    /// Whether the sensor's beacon is still the closest to each of `sensors`, and none of their
+    /// beacons is closer to it than its own.
+    fn fits_with(&self, sensors: &[PlacedSensor]) -> bool {
+        sensors.iter().all(|other| {
+            other.pos.manhattan_distance(self.beacon) >= other.range
+                && self.pos.manhattan_distance(other.beacon) >= self.range
+        })
+    }
+}
+
+/// Sensors which cover every position in the search area except `hole`, or `None` if more than
+/// `max_sensors` are needed, or no sensor could be placed to cover one of the other positions.
+fn cover_all_but(
+    hole: Vec2,
+    search_size: i32,
+    max_sensors: usize,
+    rng: &mut StdRng,
+) -> Option<Vec<PlacedSensor>> {
+    let mut sensors: Vec<PlacedSensor> = Vec::new();
+    for direction in [
+        Vec2::<i32>::UP_LEFT,
+        Vec2::UP_RIGHT,
+        Vec2::DOWN_LEFT,
+        Vec2::DOWN_RIGHT,
+    ] {
+        let offset = Vec2 {
+            x: rng.gen_range(1..=search_size.max(1)),
+            y: rng.gen_range(1..=search_size.max(1)),
+        };
+        let pos = hole + Vec2::new(offset.x * direction.x, offset.y * direction.y);
+        // The position next to the hole horizontally towards the sensor is just in range, and is
+        // no closer to the other sensors than the positions just in their ranges
+        sensors.push(PlacedSensor::new(pos, hole + Vec2::new(direction.x, 0)));
+    }
+
+    loop {
+        if sensors.len() > max_sensors {
+            return None;
+        }
+        // Cover the hole to find any other uncovered position
+        let hole_sensor = Sensor {
+            id: sensors.len(),
+            pos: hole,
+            range: 0,
+        };
+        let as_sensors: Vec<Sensor<i64>> = sensors
+            .iter()
+            .enumerate()
+            .map(|(id, sensor)| {
+                Sensor {
+                    id,
+                    pos: sensor.pos,
+                    range: sensor.range,
+                }
+                .cast()
+            })
+            .chain([hole_sensor.cast()])
+            .collect();
+        let Some(uncovered) = border_intersection::solve(&as_sensors, search_size.into()) else {
+            return Some(sensors);
+        };
+        // Positions in the search area always fit in an `i32`
+        let uncovered = Vec2::new(uncovered.x as i32, uncovered.y as i32);
+
+        // No sensor is in range of the uncovered position, so it can be the beacon of a sensor
+        // which is closer to it than to the hole and to the other beacons
+        let sensor = (0..MAX_ATTEMPTS).find_map(|_| {
+            let pos = random_pos(search_size, rng);
+            let sensor = PlacedSensor::new(pos, uncovered);
+            (sensor.range < pos.manhattan_distance(hole) && sensor.fits_with(&sensors))
+                .then_some(sensor)
+        })?;
+        sensors.push(sensor);
+    }
+}
+
+/// Random position within `search_size` of the search area, or within 1 of a search area of
+/// size 0.
+fn random_pos(search_size: i32, rng: &mut StdRng) -> Vec2 {
+    let margin = search_size.max(1);
+    Vec2 {
+        x: rng.gen_range(-margin..=search_size + margin),
+        y: rng.gen_range(-margin..=search_size + margin),
+    }
+}
+
+/// Random position on the border of a sensor's range.
+fn beacon_for(pos: Vec2, range: i32, rng: &mut StdRng) -> Vec2 {
+    let along = rng.gen_range(0..range.max(1));
+    let offset = match rng.gen_range(0..4) {
+        0 => Vec2::new(range - along, along),
+        1 => Vec2::new(-along, range - along),
+        2 => Vec2::new(along - range, -along),
+        _ => Vec2::new(along, along - range),
+    };
+    pos + offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, MAX_RANGE_DIVISOR};
+    use crate::{
+        brute_force, coverage::covered_in_area, parse, parse_with_beacons, test_case::TestCase,
+        Aabb,
+    };
+
+    #[test]
+    fn exactly_one_uncovered() {
+        for (search_size, sensor_count, seed) in [(1, 4, 0), (20, 10, 1), (4_000_000, 30, 2)] {
+            let layout = generate(search_size, sensor_count, seed).unwrap();
+            assert_eq!(layout.sensors.len(), sensor_count);
+            let area = Aabb {
+                x: 0..search_size + 1,
+                y: 0..search_size + 1,
+            };
+            assert_eq!(
+                covered_in_area(&layout.sensors, &area),
+                (search_size as u64 + 1).pow(2) - 1
+            );
+            assert!(layout.sensors.iter().all(|s| !s.covers(layout.hole)));
+        }
+    }
+
+    #[test]
+    fn small_search_areas() {
+        for search_size in 0..MAX_RANGE_DIVISOR {
+            for (sensor_count, seed) in [(4, 0), (10, 1), (30, 2)] {
+                let layout = generate(search_size, sensor_count, seed).unwrap();
+                assert_eq!(layout.sensors.len(), sensor_count);
+                assert_eq!(
+                    brute_force::solve(&layout.sensors, search_size, 2),
+                    [layout.hole],
+                    "search size {search_size}"
+                );
+            }
+        }
+    }
+
+    /// Checks that no sensor in the generated input is closer to another sensor's beacon than to
+    /// its own, as the puzzle guarantees.
+    #[test]
+    fn closest_beacons() {
+        for (search_size, sensor_count, seed) in [(0, 4, 0), (20, 30, 1), (4_000_000, 1_000, 2)] {
+            let input = generate(search_size, sensor_count, seed)
+                .unwrap()
+                .to_input();
+            let (sensors, beacons) = parse_with_beacons(&input).unwrap();
+            for sensor in &sensors {
+                let closest = beacons
+                    .iter()
+                    .map(|&beacon| sensor.pos.manhattan_distance(beacon))
+                    .min();
+                assert_eq!(closest, Some(sensor.range), "sensor {}", sensor.id());
+            }
+        }
+    }
+
+    #[test]
+    fn input_round_trip() {
+        let layout = generate(1000, 20, 3).unwrap();
+        let input = layout.to_input();
+        let sensors = parse(&input).unwrap();
+        assert_eq!(sensors.len(), layout.sensors.len());
+        for (parsed, generated) in sensors.iter().zip(&layout.sensors) {
+            assert_eq!(parsed.id(), generated.id());
+            assert_eq!(parsed.pos, generated.pos);
+            assert_eq!(parsed.range, generated.range);
+        }
+        let test_case = TestCase::parse("Generated", &input).unwrap().unwrap();
+        assert_eq!(test_case.dimension, layout.search_size);
+        assert_eq!(test_case.expected_pos, layout.hole);
+    }
+
+    #[test]
+    fn same_seed_same_layout() {
+        let input = generate(100, 12, 4).unwrap().to_input();
+        assert_eq!(generate(100, 12, 4).unwrap().to_input(), input);
+        assert_ne!(generate(100, 12, 5).unwrap().to_input(), input);
+        assert!(generate(100, 3, 4).is_none());
+    }
+}
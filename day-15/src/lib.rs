@@ -1,50 +1,197 @@
 use std::ops::Range;
 
+use aoc_common::{
+    error::{self, Line},
+    interval::RangeSet,
+    parse::{self, int, literal, Cursor},
+    ParseError, Solution,
+};
+pub use aoc_common::{geometry::Coord, Vec2};
+use metric::Metric;
+
 pub mod border_intersection;
 pub mod column_skipping;
+pub mod coverage;
+pub mod generator;
+pub mod metric;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+#[cfg(test)]
+mod properties;
 pub mod range_exclusion;
+pub mod render;
 pub mod brute_force;
+pub mod solver;
+pub mod sweep_line;
+pub mod three_d;
+
+/// Row inspected in part 1 of the actual puzzle input.
+pub const PART_1_ROW: i32 = 2_000_000;
+/// Size of the search area in part 2 of the actual puzzle input.
+pub const PART_2_SEARCH_SIZE: i32 = 4_000_000;
+/// The tuning frequency of a position is `x * TUNING_FREQUENCY_MULTIPLIER + y`.
+pub const TUNING_FREQUENCY_MULTIPLIER: i64 = 4_000_000;
+/// Row inspected in part 1 of the example puzzle input.
+pub const EXAMPLE_PART_1_ROW: i32 = 10;
+/// Size of the search area in part 2 of the example puzzle input.
+pub const EXAMPLE_PART_2_SEARCH_SIZE: i32 = 20;
+
+pub struct Day15;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Aabb {
-    x: Range<i32>,
-    y: Range<i32>,
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    pub sensors: Vec<Sensor>,
+    /// Position of the closest beacon to each sensor, in the same order as `sensors`.
+    pub beacons: Vec<Vec2>,
+    pub row: i32,
+    pub search_size: i32,
 }
 
-impl Aabb {
-    fn contains(&self, vec: &Vec2) -> bool {
-        self.x.contains(&vec.x) && self.y.contains(&vec.y)
+impl Solution for Day15 {
+    type Input = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    /// The puzzle doesn't state the row and search area size in the input. Inputs where all
+    /// sensors lie within the example's search area are treated as the example, unless a
+    /// [`Header`] gives the search area size.
+    fn parse(input: &str) -> Result<Puzzle, ParseError> {
+        let header = parse_header(input)?;
+        let (sensors, beacons) = parse_with_beacons(input)?;
+        let is_example = sensors.iter().all(|s| {
+            (0..=EXAMPLE_PART_2_SEARCH_SIZE).contains(&s.pos.x)
+                && (0..=EXAMPLE_PART_2_SEARCH_SIZE).contains(&s.pos.y)
+        });
+        let (row, search_size) = if is_example {
+            (EXAMPLE_PART_1_ROW, EXAMPLE_PART_2_SEARCH_SIZE)
+        } else {
+            (PART_1_ROW, PART_2_SEARCH_SIZE)
+        };
+        Ok(Puzzle {
+            sensors,
+            beacons,
+            row,
+            search_size: header.map_or(search_size, |header| header.search_size),
+        })
+    }
+
+    /// Number of positions in the row where a beacon cannot be present.
+    fn part_one(puzzle: &Puzzle) -> usize {
+        beaconless_in_row(&puzzle.sensors, &puzzle.beacons, puzzle.row)
+    }
+
+    /// Tuning frequency of the only position in the search area where the distress beacon could
+    /// be.
+    ///
+    /// Only accepts puzzle inputs, which always leave a position uncovered: [`Solution`] has no
+    /// way to report a missing answer, so this panics if the sensors cover the whole search area.
+    /// Use a [`solver::Part2Solver`] directly for layouts which may be fully covered.
+    fn part_two(puzzle: &Puzzle) -> i64 {
+        let pos = column_skipping::solve(&puzzle.sensors, puzzle.search_size, 1)
+            .pop()
+            .expect("the sensors leave a position in the search area uncovered");
+        tuning_frequency(pos)
     }
 }
 
-#[derive(Debug, Eq, Hash, PartialEq, Clone)]
-pub struct Vec2 {
-    pub x: i32,
-    pub y: i32,
+/// Number of positions in row `y` where a beacon cannot be present: those covered by a sensor,
+/// other than the known `beacons`.
+pub fn beaconless_in_row(sensors: &[Sensor], beacons: &[Vec2], y: i32) -> usize {
+    let beacons: RangeSet = beacons
+        .iter()
+        .filter(|beacon| beacon.y == y)
+        .map(|beacon| beacon.x)
+        .collect();
+    coverage::row_coverage(sensors, y)
+        .difference(&beacons)
+        .size() as usize
 }
 
-impl Vec2 {
-    pub const fn manhattan_distance(&self, other: &Vec2) -> i32 {
-        (self.x - other.x).abs() + (self.y - other.y).abs()
-    }
+/// Whether a beacon could be at `pos`: either one of the known `beacons` is there, or no sensor
+/// covers it, so the sensors can't rule out an unknown beacon.
+pub fn could_be_beacon(sensors: &[Sensor], beacons: &[Vec2], pos: Vec2) -> bool {
+    beacons.contains(&pos) || sensors_covering(sensors, pos).next().is_none()
+}
 
-    pub const fn sub(&self, other: &Vec2) -> Vec2 {
-        Vec2 {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
+/// Sensors which are at least as close to `pos` as to their closest beacon.
+pub fn sensors_covering(sensors: &[Sensor], pos: Vec2) -> impl Iterator<Item = &Sensor> {
+    sensors.iter().filter(move |sensor| sensor.covers(pos))
+}
+
+/// Distinct positions of the `beacons` within `area`, sorted by x then y. Several sensors often
+/// share the same closest beacon.
+pub fn beacons_in(beacons: &[Vec2], area: &Aabb) -> Vec<Vec2> {
+    let mut found: Vec<Vec2> = beacons
+        .iter()
+        .filter(|beacon| area.contains(beacon))
+        .copied()
+        .collect();
+    found.sort();
+    found.dedup();
+    found
+}
+
+pub fn tuning_frequency(pos: Vec2) -> i64 {
+    pos.x as i64 * TUNING_FREQUENCY_MULTIPLIER + pos.y as i64
+}
+
+/// Axis aligned bounding box, covering the cells with an x coordinate in `x` and a y coordinate
+/// in `y`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Aabb<T = i32> {
+    pub x: Range<T>,
+    pub y: Range<T>,
+}
+
+impl<T: Coord> Aabb<T> {
+    pub fn contains(&self, vec: &Vec2<T>) -> bool {
+        self.x.contains(&vec.x) && self.y.contains(&vec.y)
     }
 }
 
+/// Sensor, and the distance to its closest beacon. The geometry and part 2 solvers are generic
+/// over the type of the coordinates, so search areas too large for `i32` can use `i64`.
 #[derive(Debug, Clone)]
-pub struct Sensor {
+pub struct Sensor<T = i32> {
     id: usize,
-    pub pos: Vec2,
-    pub range: i32,
+    pub pos: Vec2<T>,
+    pub range: T,
+}
+
+impl<T: Coord> Sensor<T> {
+    /// Line of the puzzle input the sensor was parsed from, or its index in a test case.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Whether `pos` is within range of the sensor, so no beacon other than the sensor's closest
+    /// beacon can be there.
+    pub fn covers(&self, pos: Vec2<T>) -> bool {
+        self.covers_in(metric::Manhattan, pos)
+    }
+
+    /// The same sensor, with its coordinates converted to a wider type.
+    pub fn cast<U: Coord + From<T>>(&self) -> Sensor<U> {
+        Sensor {
+            id: self.id,
+            pos: Vec2::new(self.pos.x.into(), self.pos.y.into()),
+            range: self.range.into(),
+        }
+    }
 }
 
 pub mod test_case {
-    use crate::{Sensor, Vec2};
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use aoc_common::{ParseError, Solution};
+
+    use crate::{Day15, Puzzle, Sensor, Vec2};
+
+    /// Directory of test case files, which are discovered by [`load_dir`] and [`load_open_dir`].
+    pub const TEST_CASES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test_cases");
 
     #[derive(Debug, Clone)]
     pub struct ConstTestCase {
@@ -57,41 +204,55 @@ pub mod test_case {
     impl From<&ConstTestCase> for TestCase {
         fn from(test_case: &ConstTestCase) -> Self {
             Self {
-                name: test_case.name,
+                name: test_case.name.to_string(),
                 sensors: test_case.sensors.to_vec(),
                 dimension: test_case.dimension,
-                expected_pos: test_case.expected_pos.clone(),
+                expected_pos: test_case.expected_pos,
             }
         }
     }
+
+    /// Test case embedded from a file in [`TEST_CASES_DIR`]. The search size and expected
+    /// position are given by the file's [`Header`](crate::Header).
     #[derive(Debug, Clone)]
     pub struct FileTestCase {
         pub name: &'static str,
         pub input: &'static str,
-        pub search_size: i32,
-        pub expected_pos: Vec2,
     }
 
     impl From<&FileTestCase> for TestCase {
         fn from(file_test_case: &FileTestCase) -> Self {
-            Self {
-                name: file_test_case.name,
-                sensors: crate::parse(file_test_case.input),
-                dimension: file_test_case.search_size,
-                expected_pos: file_test_case.expected_pos.clone(),
-            }
+            TestCase::parse(file_test_case.name, file_test_case.input)
+                .unwrap_or_else(|err| panic!("{}:{err}", file_test_case.name))
+                .expect("embedded test cases name the distress beacon")
         }
     }
 
     #[derive(Debug, Clone)]
     pub struct TestCase {
-        pub name: &'static str,
+        pub name: String,
         pub sensors: Vec<Sensor>,
         pub dimension: i32,
         pub expected_pos: Vec2,
     }
 
     impl TestCase {
+        /// Parses a test case from an input with a [`Header`](crate::Header), or returns `None`
+        /// if the header doesn't name the distress beacon, which marks an input that isn't a test
+        /// case, e.g. one with several uncovered positions.
+        pub fn parse(name: &str, input: &str) -> Result<Option<TestCase>, ParseError> {
+            let header = crate::parse_header(input)?;
+            let sensors = crate::parse(input)?;
+            let header = header
+                .ok_or_else(|| ParseError::new(1, 1, "a header giving the search area size"))?;
+            Ok(header.distress_beacon.map(|expected_pos| TestCase {
+                name: name.to_string(),
+                sensors,
+                dimension: header.search_size,
+                expected_pos,
+            }))
+        }
+
         pub fn expected_answer(&self) -> i64 {
             self.expected_pos.x as i64 * self.dimension as i64 + self.expected_pos.y as i64
         }
@@ -112,12 +273,48 @@ pub mod test_case {
                 });
             }
             TestCase {
-                name: self.name,
+                name: self.name.clone(),
                 sensors,
                 dimension: self.dimension,
                 expected_pos: rotate_vec(&self.expected_pos, self.dimension),
             }
         }
+
+        /// Mirrored left to right.
+        pub fn reflected(&self) -> Self {
+            let reflect_vec = |v: &Vec2| Vec2 {
+                x: self.dimension - v.x,
+                y: v.y,
+            };
+            TestCase {
+                name: self.name.clone(),
+                sensors: self
+                    .sensors
+                    .iter()
+                    .map(|sensor| Sensor {
+                        id: sensor.id,
+                        pos: reflect_vec(&sensor.pos),
+                        range: sensor.range,
+                    })
+                    .collect(),
+                dimension: self.dimension,
+                expected_pos: reflect_vec(&self.expected_pos),
+            }
+        }
+
+        /// The test case in all 8 orientations: rotated by each multiple of 90 degrees, with and
+        /// without being mirrored.
+        pub fn symmetries(&self) -> Vec<Self> {
+            let mut symmetries = Vec::new();
+            for mut case in [self.clone(), self.reflected()] {
+                for _ in 0..4 {
+                    let rotated = case.rotated();
+                    symmetries.push(case);
+                    case = rotated;
+                }
+            }
+            symmetries
+        }
     }
 
     pub const HALL: ConstTestCase = ConstTestCase {
@@ -368,28 +565,13 @@ pub mod test_case {
     pub const AOC_ACTUAL: FileTestCase = FileTestCase {
         name: "AOC Actual",
         input: include_str!("../test_cases/aoc_actual.txt"),
-        search_size: 4_000_000,
-        expected_pos: Vec2 {
-            x: 3270298,
-            y: 2638237,
-        },
     };
 
     pub const AOC_EXAMPLE: FileTestCase = FileTestCase {
         name: "AOC Example",
         input: include_str!("../test_cases/aoc_example.txt"),
-        search_size: 20,
-        expected_pos: Vec2 { x: 14, y: 11 },
     };
 
-    pub const SMALL_FILE: FileTestCase = FileTestCase {
-        name: "Small 1",
-        input: include_str!("../test_cases/small_1.txt"),
-        search_size: 2,
-        expected_pos: Vec2 { x: 1, y: 1 },
-    };
-
-    pub const FILE_TEST_CASES: &[FileTestCase] = &[AOC_ACTUAL, AOC_EXAMPLE, SMALL_FILE];
     pub const CONST_TEST_CASES: &[ConstTestCase] = &[
         HALL,
         MINIMAL,
@@ -402,42 +584,179 @@ pub mod test_case {
         SMALL_CONST,
     ];
 
+    /// Test cases from every `.txt` file in `dir`, sorted by file name and named after it.
+    /// Files whose header doesn't name the distress beacon are left to [`load_open_dir`], but a
+    /// file without a header is an error, as is a file which can't be read.
+    pub fn load_dir(dir: &Path) -> Result<Vec<TestCase>, String> {
+        let mut test_cases = Vec::new();
+        for (path, name, input) in read_dir(dir)? {
+            match TestCase::parse(&name, &input) {
+                Ok(Some(test_case)) => test_cases.push(test_case),
+                Ok(None) => {}
+                Err(err) => return Err(format!("{}:{err}", path.display())),
+            }
+        }
+        Ok(test_cases)
+    }
+
+    /// Layouts from every `.txt` file in `dir` whose header doesn't name the distress beacon,
+    /// usually because several positions are uncovered, paired with the file name. The solvers
+    /// are checked against brute force on these rather than against an expected position.
+    pub fn load_open_dir(dir: &Path) -> Result<Vec<(String, Puzzle)>, String> {
+        let mut layouts = Vec::new();
+        for (path, name, input) in read_dir(dir)? {
+            let header =
+                crate::parse_header(&input).map_err(|err| format!("{}:{err}", path.display()))?;
+            if header.is_some_and(|header| header.distress_beacon.is_none()) {
+                let puzzle =
+                    Day15::parse(&input).map_err(|err| format!("{}:{err}", path.display()))?;
+                layouts.push((name, puzzle));
+            }
+        }
+        Ok(layouts)
+    }
+
+    /// Path, file stem and contents of every `.txt` file in `dir`, sorted by file name.
+    fn read_dir(dir: &Path) -> Result<Vec<(PathBuf, String, String)>, String> {
+        let entries = fs::read_dir(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+        let mut paths = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|err| format!("{}: {err}", dir.display()))?
+                .path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut files = Vec::new();
+        for path in paths {
+            let input =
+                fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+            let name = path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned();
+            files.push((path, name, input));
+        }
+        Ok(files)
+    }
+
+    /// Test cases from every file in [`TEST_CASES_DIR`], for tests and benchmarks.
+    pub fn file_test_cases() -> Vec<TestCase> {
+        load_dir(Path::new(TEST_CASES_DIR)).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Layouts from every file in [`TEST_CASES_DIR`] which isn't a test case, for tests.
+    pub fn file_open_layouts() -> Vec<(String, Puzzle)> {
+        load_open_dir(Path::new(TEST_CASES_DIR)).unwrap_or_else(|err| panic!("{err}"))
+    }
+
     pub fn get_test_cases() -> impl Iterator<Item = TestCase> {
         let a = CONST_TEST_CASES.iter().map(Into::<TestCase>::into);
-        let b = FILE_TEST_CASES.iter().map(Into::<TestCase>::into);
-        a.chain(b)
+        a.chain(file_test_cases())
     }
 }
 
-pub fn parse(input: &str) -> Vec<Sensor> {
-    fn parse_int(s: &str, trailing_chars: usize) -> i32 {
-        (s[2..s.len() - trailing_chars]).parse().unwrap()
-    }
+pub fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    Ok(parse_with_beacons(input)?.0)
+}
 
-    let mut sensors = Vec::new();
-    for (line_idx, line) in input.lines().enumerate() {
-        let mut words = line.split_ascii_whitespace().skip(2);
-        let sensor_pos = Vec2 {
-            x: parse_int(words.next().unwrap(), 1),
-            y: parse_int(words.next().unwrap(), 1),
-        };
-        let mut words = words.skip(4);
-        let beacon = Vec2 {
-            x: parse_int(words.next().unwrap(), 1),
-            y: parse_int(words.next().unwrap(), 0),
-        };
+/// Parses the sensors, with each one's range measured to its closest beacon using `metric`
+/// rather than the Manhattan distance, into coordinates of type `T`. Ranges which don't fit in
+/// `T`, such as squared Euclidean distances in `i32`, are an error.
+pub fn parse_in<T: Coord + From<i32>>(
+    input: &str,
+    metric: impl Metric,
+) -> Result<Vec<Sensor<T>>, ParseError> {
+    let (sensors, beacons) = parse_with_beacons(input)?;
+    sensors
+        .iter()
+        .zip(beacons)
+        .map(|(sensor, beacon)| {
+            let sensor: Sensor<T> = sensor.cast();
+            let beacon = Vec2::new(beacon.x.into(), beacon.y.into());
+            let range = metric.checked_distance(sensor.pos, beacon).ok_or_else(|| {
+                ParseError::new(sensor.id, 1, "a sensor whose range fits in the coordinates")
+            })?;
+            Ok(Sensor { range, ..sensor })
+        })
+        .collect()
+}
 
-        let range = sensor_pos.manhattan_distance(&beacon);
+/// Parses the sensors, and the position of the closest beacon to each sensor. The input's
+/// [`Header`], if it has one, is skipped.
+pub fn parse_with_beacons(input: &str) -> Result<(Vec<Sensor>, Vec<Vec2>), ParseError> {
+    let mut sensors = Vec::new();
+    let mut beacons = Vec::new();
+    for line in error::lines(input).filter(|line| !is_header(line)) {
+        let (sensor_pos, beacon) = parse::parse_line(line, |cursor| {
+            literal("Sensor at ")(cursor)?;
+            let sensor_pos = parse_position(cursor)?;
+            literal(": closest beacon is at ")(cursor)?;
+            Ok((sensor_pos, parse_position(cursor)?))
+        })?;
+
+        let range = sensor_pos.manhattan_distance(beacon);
         sensors.push(Sensor {
             pos: sensor_pos,
-            id: line_idx + 1,
+            id: line.number,
             range,
         });
+        beacons.push(beacon);
     }
-    sensors
+    Ok((sensors, beacons))
 }
 
-/// Draw map for debugging.
+/// Optional first line of an input, which isn't part of the puzzle's format, giving the size of
+/// the search area and, for test cases, the position of the distress beacon:
+///
+/// ```text
+/// Search area size 20: distress beacon is at x=14, y=11
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    pub search_size: i32,
+    pub distress_beacon: Option<Vec2>,
+}
+
+/// Parses the input's header, or returns `None` if it doesn't have one.
+pub fn parse_header(input: &str) -> Result<Option<Header>, ParseError> {
+    let Some(line) = error::lines(input).next().filter(is_header) else {
+        return Ok(None);
+    };
+    parse::parse_line(line, |cursor| {
+        literal(HEADER_PREFIX)(cursor)?;
+        let search_size = int(cursor)?;
+        let distress_beacon = parse::opt(|cursor: &mut Cursor| {
+            literal(": distress beacon is at ")(cursor)?;
+            parse_position(cursor)
+        })(cursor)?;
+        Ok(Some(Header {
+            search_size,
+            distress_beacon,
+        }))
+    })
+}
+
+fn is_header(line: &Line) -> bool {
+    line.number == 1 && line.text.starts_with(HEADER_PREFIX)
+}
+
+/// Parses a position of the form "x=2, y=18".
+fn parse_position(cursor: &mut Cursor) -> Result<Vec2, ParseError> {
+    literal("x=")(cursor)?;
+    let x = int(cursor)?;
+    literal(", y=")(cursor)?;
+    let y = int(cursor)?;
+    Ok(Vec2 { x, y })
+}
+
+const HEADER_PREFIX: &str = "Search area size ";
+
+/// Draw map for debugging. See [`render`] for layouts which are too large for this.
 ///
 /// Warnings:
 /// - Breaks if there are more sensors than alphanumeric characters (36).
@@ -464,7 +783,7 @@ where
                     sensor_range = Some(sensor.range);
                     sensor_line = Some(sensor.id);
                 }
-                let distance = sensor.pos.manhattan_distance(&Vec2 { x, y });
+                let distance = sensor.pos.manhattan_distance(Vec2 { x, y });
                 if distance <= sensor.range {
                     overlap_cnt += 1;
                     if distance == sensor.range {
@@ -488,3 +807,115 @@ where
         println!("{line_2}");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{ParseError, Solution};
+
+    use crate::{
+        beaconless_in_row, beacons_in, could_be_beacon, parse, parse_header, parse_with_beacons,
+        sensors_covering,
+        test_case::{self, TestCase},
+        Aabb, Day15, Header, Vec2,
+    };
+
+    #[test]
+    fn parse_sensor() {
+        let sensors = parse("Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n").unwrap();
+        assert_eq!(sensors[0].pos, Vec2 { x: 2, y: 18 });
+        assert_eq!(sensors[0].range, 7);
+    }
+
+    #[test]
+    fn parse_error() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n\
+                     Sensor at x=9, y=16: closest beacon is at x=10, y=\n";
+        assert_eq!(
+            parse(input).unwrap_err(),
+            ParseError::new(2, 51, "an integer")
+        );
+    }
+
+    #[test]
+    fn header() {
+        let sensor = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+        let input = format!("Search area size 20\n{sensor}\n");
+        let sensors = parse(&input).unwrap();
+        assert_eq!(
+            parse_header(&input).unwrap(),
+            Some(Header {
+                search_size: 20,
+                distress_beacon: None
+            })
+        );
+        assert_eq!(sensors[0].id(), 2);
+        let input = format!("Search area size 30: distress beacon is at x=4, y=-1\n{sensor}\n");
+        let puzzle = Day15::parse(&input).unwrap();
+        assert_eq!(puzzle.search_size, 30);
+        let test_case = TestCase::parse("Header", &input).unwrap().unwrap();
+        assert_eq!(test_case.expected_pos, Vec2 { x: 4, y: -1 });
+
+        assert_eq!(parse_header(sensor).unwrap(), None);
+        assert_eq!(
+            TestCase::parse("No header", sensor).unwrap_err(),
+            ParseError::new(1, 1, "a header giving the search area size")
+        );
+        assert_eq!(
+            parse_header("Search area size 20: distress beacon\n").unwrap_err(),
+            ParseError::new(1, 20, "end of line")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the sensors leave a position in the search area uncovered")]
+    fn fully_covered_part_2() {
+        let input = "Search area size 2\nSensor at x=1, y=1: closest beacon is at x=3, y=1\n";
+        Day15::part_two(&Day15::parse(input).unwrap());
+    }
+
+    #[test]
+    fn example_part_1() {
+        let (sensors, beacons) = parse_with_beacons(test_case::AOC_EXAMPLE.input).unwrap();
+        assert_eq!(beaconless_in_row(&sensors, &beacons, 10), 26);
+        for y in -10..30 {
+            let brute_force = (-20..40)
+                .map(|x| Vec2 { x, y })
+                .filter(|pos| !beacons.contains(pos))
+                .filter(|&pos| sensors.iter().any(|s| s.covers(pos)))
+                .count();
+            assert_eq!(
+                beaconless_in_row(&sensors, &beacons, y),
+                brute_force,
+                "row {y}"
+            );
+        }
+    }
+
+    #[test]
+    fn beacon_queries() {
+        let (sensors, beacons) = parse_with_beacons(test_case::AOC_EXAMPLE.input).unwrap();
+        let covering = |x, y| -> Vec<usize> {
+            sensors_covering(&sensors, Vec2 { x, y })
+                .map(|sensor| sensor.id())
+                .collect()
+        };
+        // Sensors are identified by their line number, after the header
+        assert_eq!(covering(2, 10), vec![8, 9, 10]);
+        assert_eq!(covering(14, 11), vec![]);
+        assert!(could_be_beacon(&sensors, &beacons, Vec2 { x: 2, y: 10 }));
+        assert!(could_be_beacon(&sensors, &beacons, Vec2 { x: 14, y: 11 }));
+        assert!(could_be_beacon(&sensors, &beacons, Vec2 { x: -8, y: 10 }));
+        assert!(!could_be_beacon(&sensors, &beacons, Vec2 { x: 3, y: 10 }));
+
+        let search_area = Aabb { x: 0..21, y: 0..21 };
+        assert_eq!(
+            beacons_in(&beacons, &search_area),
+            [(2, 10), (10, 16), (15, 3)].map(|(x, y)| Vec2 { x, y })
+        );
+        let everywhere = Aabb {
+            x: i32::MIN..i32::MAX,
+            y: i32::MIN..i32::MAX,
+        };
+        assert_eq!(beacons_in(&beacons, &everywhere).len(), 6);
+    }
+}
@@ -0,0 +1,304 @@
+//! Distance metrics, which give the shape of the area covered by each sensor. The puzzle uses the
+//! [`Manhattan`] distance, so a sensor covers a diamond. With the [`Chebyshev`] distance it covers
+//! a square, and with the [`Euclidean`] distance a disc.
+//!
+//! A sensor's range is the distance to its closest beacon in the metric it is used with (see
+//! [`crate::parse_in`]). Only brute force and column skipping are generic over the metric; the
+//! other solvers rely on the borders of the sensors' ranges being diagonal lines.
+
+use crate::{Coord, Sensor, Vec2};
+
+/// Distance between positions, in the units of [`Sensor::range`].
+pub trait Metric: Copy + Send + Sync {
+    /// Human readable name, used to label test failures.
+    fn name(self) -> &'static str;
+
+    /// Distance from `a` to `b`.
+    fn distance<T: Coord>(self, a: Vec2<T>, b: Vec2<T>) -> T;
+
+    /// Distance from `a` to `b`, or `None` if it doesn't fit in `T`.
+    fn checked_distance<T: Coord>(self, a: Vec2<T>, b: Vec2<T>) -> Option<T>;
+
+    /// Whether `b` is within `range` of `a`. A distance too large for `T` is out of range.
+    fn within<T: Coord>(self, a: Vec2<T>, b: Vec2<T>, range: T) -> bool {
+        self.checked_distance(a, b)
+            .is_some_and(|distance| distance <= range)
+    }
+
+    /// Furthest distance along the x axis which is within `range`, at a distance of `dy` along the
+    /// y axis, or `None` if no position that far along the y axis is within `range`.
+    fn reach<T: Coord>(self, range: T, dy: T) -> Option<T>;
+}
+
+/// Sum of the distances along each axis. Sensors cover diamonds, as in the puzzle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Manhattan;
+
+impl Metric for Manhattan {
+    fn name(self) -> &'static str {
+        "Manhattan"
+    }
+
+    fn distance<T: Coord>(self, a: Vec2<T>, b: Vec2<T>) -> T {
+        a.manhattan_distance(b)
+    }
+
+    fn checked_distance<T: Coord>(self, a: Vec2<T>, b: Vec2<T>) -> Option<T> {
+        let (dx, dy) = offset(a, b)?;
+        T::from_i128(dx.checked_add(dy)?)
+    }
+
+    // Unchecked, as the distance only overflows for coordinates beyond half of `T`'s range, and
+    // the checks are slow in the solvers' inner loops
+    fn within<T: Coord>(self, a: Vec2<T>, b: Vec2<T>, range: T) -> bool {
+        self.distance(a, b) <= range
+    }
+
+    fn reach<T: Coord>(self, range: T, dy: T) -> Option<T> {
+        let reach = range - dy.abs();
+        (reach >= T::ZERO).then_some(reach)
+    }
+}
+
+/// Largest of the distances along each axis. Sensors cover squares.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Chebyshev;
+
+impl Metric for Chebyshev {
+    fn name(self) -> &'static str {
+        "Chebyshev"
+    }
+
+    fn distance<T: Coord>(self, a: Vec2<T>, b: Vec2<T>) -> T {
+        a.chebyshev_distance(b)
+    }
+
+    fn checked_distance<T: Coord>(self, a: Vec2<T>, b: Vec2<T>) -> Option<T> {
+        let (dx, dy) = offset(a, b)?;
+        T::from_i128(dx.max(dy))
+    }
+
+    // Unchecked, as for Manhattan distances
+    fn within<T: Coord>(self, a: Vec2<T>, b: Vec2<T>, range: T) -> bool {
+        self.distance(a, b) <= range
+    }
+
+    fn reach<T: Coord>(self, range: T, dy: T) -> Option<T> {
+        (dy.abs() <= range).then_some(range)
+    }
+}
+
+/// Straight line distance, squared so that it is always an integer. Sensors cover discs.
+///
+/// Ranges are squared distances too, so they overflow `i32` for sensors more than about 46,000
+/// positions from their closest beacon, such as in the actual puzzle input. Parse those with `i64`
+/// coordinates, e.g. `parse_in::<i64>(input, Euclidean)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Euclidean;
+
+impl Metric for Euclidean {
+    fn name(self) -> &'static str {
+        "Euclidean"
+    }
+
+    /// Panics if the squared distance doesn't fit in `T`, rather than wrapping around.
+    fn distance<T: Coord>(self, a: Vec2<T>, b: Vec2<T>) -> T {
+        self.checked_distance(a, b)
+            .expect("the squared distance fits in the coordinate type")
+    }
+
+    fn checked_distance<T: Coord>(self, a: Vec2<T>, b: Vec2<T>) -> Option<T> {
+        let (dx, dy) = offset(a, b)?;
+        T::from_i128(dx.checked_mul(dx)?.checked_add(dy.checked_mul(dy)?)?)
+    }
+
+    fn reach<T: Coord>(self, range: T, dy: T) -> Option<T> {
+        let remaining = range.to_i128() - dy.to_i128().pow(2);
+        (remaining >= 0).then(|| {
+            T::from_i128(remaining.isqrt()).expect("the reach is no larger than the range")
+        })
+    }
+}
+
+/// Absolute differences of the coordinates of `a` and `b`, or `None` if they don't fit in an
+/// `i128`.
+fn offset<T: Coord>(a: Vec2<T>, b: Vec2<T>) -> Option<(i128, i128)> {
+    let dx = a.x.to_i128().checked_sub(b.x.to_i128())?;
+    let dy = a.y.to_i128().checked_sub(b.y.to_i128())?;
+    Some((dx.checked_abs()?, dy.checked_abs()?))
+}
+
+impl<T: Coord> Sensor<T> {
+    /// Whether `pos` is within range of the sensor, measuring distances with `metric`.
+    pub fn covers_in(&self, metric: impl Metric, pos: Vec2<T>) -> bool {
+        metric.within(self.pos, pos, self.range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chebyshev, Euclidean, Manhattan, Metric};
+    use crate::{brute_force, column_skipping, parse_in, test_case::TestCase, Sensor, Vec2};
+
+    /// Checks that `metric` agrees with [`Metric::reach`] about which positions around the origin
+    /// are within each range.
+    fn check_reach(metric: impl Metric) {
+        for range in 0i32..30 {
+            for dy in -8..=8 {
+                let reach = metric.reach(range, dy);
+                for dx in -12..=12 {
+                    let within = metric.distance(Vec2::ZERO, Vec2::new(dx, dy)) <= range;
+                    assert_eq!(
+                        within,
+                        reach.is_some_and(|reach| dx.abs() <= reach),
+                        "{}: range {range}, offset ({dx}, {dy})",
+                        metric.name()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reach() {
+        check_reach(Manhattan);
+        check_reach(Chebyshev);
+        check_reach(Euclidean);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2::new(2, 18);
+        let b = Vec2::new(-2, 15);
+        assert_eq!(Manhattan.distance(a, b), 7);
+        assert_eq!(Chebyshev.distance(a, b), 4);
+        assert_eq!(Euclidean.distance(a, b), 25);
+        assert_eq!(Euclidean.distance(b, a), 25);
+        assert_eq!(Euclidean.reach(i64::MAX, 0), Some(3_037_000_499));
+    }
+
+    #[test]
+    fn checked_distances() {
+        let a = Vec2::new(2_000_000, 3_000_000);
+        let b = Vec2::new(-1_000_000, 0);
+        assert_eq!(Manhattan.checked_distance(a, b), Some(6_000_000));
+        assert_eq!(Chebyshev.checked_distance(a, b), Some(3_000_000));
+        assert_eq!(Euclidean.checked_distance(a, b), None);
+        let (a, b) = (
+            Vec2::new(a.x as i64, a.y as i64),
+            Vec2::new(b.x as i64, b.y as i64),
+        );
+        assert_eq!(Euclidean.checked_distance(a, b), Some(18_000_000_000_000));
+        assert_eq!(Euclidean.distance(a, b), 18_000_000_000_000);
+        let far = Vec2::new(i64::MAX, 0);
+        assert_eq!(Manhattan.checked_distance(far, Vec2::new(-1, 0)), None);
+        assert_eq!(Euclidean.checked_distance(far, Vec2::ZERO), None);
+    }
+
+    #[test]
+    fn distant_sensors() {
+        // The squared distance to the search area doesn't fit in an i32
+        let far = Sensor {
+            id: 0,
+            pos: Vec2::new(100_000, 0),
+            range: 1,
+        };
+        assert!(!far.covers_in(Euclidean, Vec2::ZERO));
+        assert!(far.covers_in(Euclidean, Vec2::new(100_000, 1)));
+        let sensors = [far];
+        assert_eq!(brute_force::solve_in(Euclidean, &sensors, 2, 100).len(), 9);
+        assert_eq!(
+            column_skipping::solve_in(Euclidean, &sensors, 2, 100).len(),
+            9
+        );
+    }
+
+    #[test]
+    fn parse_ranges() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n";
+        assert_eq!(parse_in::<i32>(input, Manhattan).unwrap()[0].range, 7);
+        assert_eq!(parse_in::<i32>(input, Chebyshev).unwrap()[0].range, 4);
+        assert_eq!(parse_in::<i32>(input, Euclidean).unwrap()[0].range, 25);
+    }
+
+    #[test]
+    fn parse_large_euclidean_ranges() {
+        // The first sensor of the actual puzzle input, about 1,000,000 from its closest beacon
+        let input = "Sensor at x=2302110, y=2237242: closest beacon is at x=2348729, y=1239977\n";
+        let sensors = parse_in::<i64>(input, Euclidean).unwrap();
+        assert_eq!(sensors[0].range, 46_619i64.pow(2) + 997_265i64.pow(2));
+        assert_eq!(sensors[0].pos, Vec2::new(2_302_110, 2_237_242));
+        assert_eq!(
+            parse_in::<i64>(input, Manhattan).unwrap()[0].range,
+            1_043_884
+        );
+
+        let err = parse_in::<i32>(input, Euclidean).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    fn test_case(sensors: &[(i32, i32, i32)], dimension: i32, expected_pos: Vec2) -> TestCase {
+        TestCase {
+            name: "Metric".to_string(),
+            sensors: sensors
+                .iter()
+                .enumerate()
+                .map(|(id, &(x, y, range))| Sensor {
+                    id,
+                    pos: Vec2 { x, y },
+                    range,
+                })
+                .collect(),
+            dimension,
+            expected_pos,
+        }
+    }
+
+    /// Checks that brute force and column skipping find the only uncovered position in `case`,
+    /// in all 8 orientations, with coordinates of type `i32` and `i64`.
+    fn check(metric: impl Metric, case: &TestCase) {
+        for case in case.symmetries() {
+            let expected = vec![case.expected_pos];
+            let found = [
+                brute_force::solve_in(metric, &case.sensors, case.dimension, 2),
+                column_skipping::solve_in(metric, &case.sensors, case.dimension, 2),
+            ];
+            assert_eq!(found, [expected.clone(), expected], "{}", metric.name());
+
+            let sensors: Vec<Sensor<i64>> = case.sensors.iter().map(Sensor::cast).collect();
+            let expected = Vec2::new(case.expected_pos.x.into(), case.expected_pos.y.into());
+            let found = column_skipping::solve_in(metric, &sensors, case.dimension.into(), 1);
+            assert_eq!(found, [expected], "{}", metric.name());
+        }
+    }
+
+    #[test]
+    fn chebyshev_test_case() {
+        let case = test_case(
+            &[(4, 0, 2), (-1, 0, 1), (1, 5, 3), (1, -1, 1)],
+            4,
+            Vec2 { x: 1, y: 1 },
+        );
+        check(Chebyshev, &case);
+        // The sensors cover less as diamonds
+        assert_eq!(
+            brute_force::solve(&case.sensors, case.dimension, 100).len(),
+            9
+        );
+    }
+
+    #[test]
+    fn euclidean_test_case() {
+        let case = test_case(&[(3, 5, 6), (3, 0, 11), (-2, 2, 9)], 4, Vec2 { x: 1, y: 3 });
+        check(Euclidean, &case);
+        // The sensors cover everything as squares
+        assert!(brute_force::solve_in(Chebyshev, &case.sensors, case.dimension, 1).is_empty());
+    }
+
+    #[test]
+    fn manhattan_test_cases() {
+        for case in crate::test_case::CONST_TEST_CASES {
+            check(Manhattan, &case.into());
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Multi-threaded versions of the column skipping and border intersection solvers, enabled by the
+//! `parallel` feature.
+//!
+//! The work is spread across rayon's current thread pool, so the number of threads can be chosen
+//! by running a solver inside [`rayon::ThreadPool::install`].
+//!
+//! Each task counts its work separately, and adds it to the total [`Stats`] when it finishes, so
+//! the counts include the work of tasks which were still running when the result was found.
+
+use std::sync::Mutex;
+
+use rayon::prelude::*;
+
+use crate::{
+    border_intersection::{border_segments, solve_at_intersection, solve_on_edges},
+    column_skipping::{scan_rows, ColumnSkipping},
+    metric::Manhattan,
+    solver::{Part2Solver, Stats},
+    Coord, Sensor, Vec2,
+};
+
+/// Number of strips per thread which the search area is split into by [`ParallelColumnSkipping`].
+/// Uncovered positions are rare, so most strips take a similar time to scan, but using more
+/// strips than threads evens out the rest.
+const STRIPS_PER_THREAD: i128 = 16;
+
+/// Column skipping, with the search area split into horizontal strips which are scanned
+/// concurrently.
+pub struct ParallelColumnSkipping;
+
+impl<T: Coord> Part2Solver<T> for ParallelColumnSkipping {
+    fn name(&self) -> &'static str {
+        "Parallel Column Skipping"
+    }
+
+    fn solve_with_stats(&self, sensors: &[Sensor<T>], search_size: T) -> (Option<Vec2<T>>, Stats) {
+        let total = Mutex::new(Stats::default());
+        let pos = strips(search_size)
+            .into_par_iter()
+            .find_map_any(|(start, end)| {
+                let mut stats = Stats::default();
+                let pos =
+                    scan_rows(Manhattan, sensors, search_size, start..end, 1, &mut stats).pop();
+                *total.lock().unwrap() += stats;
+                pos
+            });
+        (pos, total.into_inner().unwrap())
+    }
+
+    /// Uncovered positions ordered by y and then x, like [`crate::column_skipping::solve`].
+    fn uncovered(&self, sensors: &[Sensor<T>], search_size: T, limit: usize) -> Vec<Vec2<T>> {
+        let strips: Vec<Vec<Vec2<T>>> = strips(search_size)
+            .into_par_iter()
+            .map(|(start, end)| {
+                scan_rows(
+                    Manhattan,
+                    sensors,
+                    search_size,
+                    start..end,
+                    limit,
+                    &mut Stats::default(),
+                )
+            })
+            .collect();
+        strips.into_iter().flatten().take(limit).collect()
+    }
+
+    fn is_practical(&self, search_size: T) -> bool {
+        ColumnSkipping.is_practical(search_size)
+    }
+}
+
+/// Splits the rows of the search area into consecutive strips, as pairs of the first row in the
+/// strip and the row after the last.
+fn strips<T: Coord>(search_size: T) -> Vec<(T, T)> {
+    let rows = search_size.to_i128() + 1;
+    let strip_count = (rayon::current_num_threads() as i128 * STRIPS_PER_THREAD).clamp(1, rows);
+    let strip_height = (rows + strip_count - 1) / strip_count;
+    let row = |row: i128| T::from_i128(row).expect("rows are within the search area");
+    (0..strip_count)
+        .map(|strip| strip * strip_height)
+        .take_while(|&start| start < rows)
+        .map(|start| (row(start), row((start + strip_height).min(rows))))
+        .collect()
+}
+
+/// Border intersection, with the intersections of each positive slope segment with every
+/// negative slope segment checked concurrently.
+pub struct ParallelBorderIntersection;
+
+impl<T: Coord> Part2Solver<T> for ParallelBorderIntersection {
+    fn name(&self) -> &'static str {
+        "Parallel Border Intersection"
+    }
+
+    fn solve_with_stats(&self, sensors: &[Sensor<T>], search_size: T) -> (Option<Vec2<T>>, Stats) {
+        let mut stats = Stats::default();
+        if let Some(pos) = solve_on_edges(sensors, search_size, &mut stats) {
+            return (Some(pos), stats);
+        }
+        let total = Mutex::new(stats);
+        let (pos_slope_segments, neg_slope_segments) = border_segments(sensors);
+        let pos = pos_slope_segments
+            .par_iter()
+            .find_map_any(|pos_slope_segment| {
+                let mut stats = Stats::default();
+                let pos = neg_slope_segments.iter().find_map(|neg_slope_segment| {
+                    solve_at_intersection(
+                        pos_slope_segment,
+                        neg_slope_segment,
+                        sensors,
+                        search_size,
+                        &mut stats,
+                    )
+                });
+                *total.lock().unwrap() += stats;
+                pos
+            });
+        (pos, total.into_inner().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strips;
+
+    #[test]
+    fn strips_cover_every_row_once() {
+        for search_size in [0, 1, 2, 20, 999, 4_000_000] {
+            let strips = strips(search_size);
+            assert_eq!(strips.first().unwrap().0, 0);
+            assert_eq!(strips.last().unwrap().1, search_size + 1);
+            assert!(strips.windows(2).all(|pair| pair[0].1 == pair[1].0));
+            assert!(strips.iter().all(|(start, end)| start < end));
+        }
+        assert_eq!(strips(6_000_000_000i64).last().unwrap().1, 6_000_000_001);
+    }
+}
@@ -0,0 +1,201 @@
+//! Property tests cross-checking every part 2 solver against brute force, on small random sensor
+//! layouts in all 8 orientations, and column skipping against brute force in every
+//! [`Metric`](crate::metric::Metric). The 3D solver is cross-checked against brute force on small
+//! search cubes, with every part 2 solver checking the slices.
+//!
+//! Proptest saves the seed of each shrunk failing case in `proptest-regressions/properties.txt`,
+//! and reruns those cases before generating new ones, so the file should be committed with the
+//! fix. Layouts with exactly one uncovered position are valid puzzle inputs, so if a solver gets
+//! one of those wrong, the failure message also includes the shrunk layout as a
+//! [`ConstTestCase`] definition. It isn't added to [`crate::test_case`] automatically: paste it
+//! into `CONST_TEST_CASES` by hand to check every solver against it by name.
+//!
+//! [`ConstTestCase`]: crate::test_case::ConstTestCase
+
+use std::fmt::Write;
+
+use proptest::{prelude::*, test_runner::FileFailurePersistence};
+
+use crate::{
+    brute_force, column_skipping,
+    metric::{Chebyshev, Euclidean, Manhattan, Metric},
+    solver::{solvers, Part2Solver},
+    test_case::TestCase,
+    three_d::{self, Sensor3, Vec3},
+    Sensor, Vec2,
+};
+
+/// Small search area, with sensors in and around it.
+fn layout() -> impl Strategy<Value = TestCase> {
+    (1..=8).prop_flat_map(|dimension| {
+        let sensor = (-3..=dimension + 3, -3..=dimension + 3, 0..=dimension + 2);
+        prop::collection::vec(sensor, 1..=8).prop_map(move |sensors| TestCase {
+            name: "Property".to_string(),
+            sensors: sensors
+                .into_iter()
+                .enumerate()
+                .map(|(id, (x, y, range))| Sensor {
+                    id,
+                    pos: Vec2 { x, y },
+                    range,
+                })
+                .collect(),
+            dimension,
+            // Filled in by `puzzle` for layouts with exactly one uncovered position
+            expected_pos: Vec2::ZERO,
+        })
+    })
+}
+
+/// Small search cube, with 3D sensors in and around it, and its size.
+fn layout_3d() -> impl Strategy<Value = (Vec<Sensor3>, i32)> {
+    (1..=5).prop_flat_map(|dimension| {
+        let sensor = (
+            -2..=dimension + 2,
+            -2..=dimension + 2,
+            -2..=dimension + 2,
+            0..=dimension + 3,
+        );
+        let sensors = prop::collection::vec(sensor, 1..=10).prop_map(|sensors| {
+            sensors
+                .into_iter()
+                .enumerate()
+                .map(|(id, (x, y, z, range))| Sensor3::new(id, Vec3::new(x, y, z), range))
+                .collect()
+        });
+        (sensors, Just(dimension))
+    })
+}
+
+/// Small layout with exactly one uncovered position, like a puzzle input.
+fn puzzle() -> impl Strategy<Value = TestCase> {
+    layout().prop_filter_map(
+        "not exactly one uncovered position",
+        |case| match brute_force::solve(&case.sensors, case.dimension, 2)[..] {
+            [expected_pos] => Some(TestCase {
+                expected_pos,
+                ..case
+            }),
+            _ => None,
+        },
+    )
+}
+
+/// Source code of a [`crate::test_case::ConstTestCase`] with the same sensors and answer as
+/// `case`.
+fn const_test_case_source(case: &TestCase) -> String {
+    let mut source = String::from("pub const SHRUNK: ConstTestCase = ConstTestCase {\n");
+    source += "    name: \"Shrunk\",\n    sensors: &[\n";
+    for sensor in &case.sensors {
+        writeln!(
+            source,
+            "        Sensor {{\n            id: {},\n            pos: Vec2 {{ x: {}, y: {} }},\n            range: {},\n        }},",
+            sensor.id, sensor.pos.x, sensor.pos.y, sensor.range
+        )
+        .unwrap();
+    }
+    writeln!(source, "    ],\n    dimension: {},", case.dimension).unwrap();
+    writeln!(
+        source,
+        "    expected_pos: Vec2 {{ x: {}, y: {} }},\n}};",
+        case.expected_pos.x, case.expected_pos.y
+    )
+    .unwrap();
+    source
+}
+
+/// Checks `solver` against the uncovered positions found by brute force, of which there may be
+/// any number.
+fn check_uncovered(solver: &dyn Part2Solver, case: &TestCase) -> Result<(), TestCaseError> {
+    let mut expected = brute_force::solve(&case.sensors, case.dimension, usize::MAX);
+    expected.sort();
+    match solver.solve(&case.sensors, case.dimension) {
+        Some(pos) => prop_assert!(expected.contains(&pos), "{}: {pos:?}", solver.name()),
+        None => prop_assert!(expected.is_empty(), "{}: None", solver.name()),
+    }
+    let mut uncovered = solver.uncovered(&case.sensors, case.dimension, expected.len() + 1);
+    uncovered.sort();
+    prop_assert_eq!(uncovered, expected, "{}", solver.name());
+    let none = solver.uncovered(&case.sensors, case.dimension, 0);
+    prop_assert_eq!(none, vec![], "{} with a limit of 0", solver.name());
+    Ok(())
+}
+
+/// Checks column skipping against the uncovered positions found by brute force in `metric`.
+fn check_metric(metric: impl Metric, case: &TestCase) -> Result<(), TestCaseError> {
+    let mut expected = brute_force::solve_in(metric, &case.sensors, case.dimension, usize::MAX);
+    expected.sort();
+    let mut uncovered =
+        column_skipping::solve_in(metric, &case.sensors, case.dimension, usize::MAX);
+    uncovered.sort();
+    prop_assert_eq!(uncovered, expected, "{}", metric.name());
+    Ok(())
+}
+
+proptest! {
+    // Fewer than 1 in 10 layouts have exactly one uncovered position
+    #![proptest_config(ProptestConfig {
+        max_local_rejects: 1_000_000,
+        failure_persistence: Some(Box::new(FileFailurePersistence::SourceParallel(
+            "proptest-regressions"
+        ))),
+        ..ProptestConfig::default()
+    })]
+
+    #[test]
+    fn solvers_find_the_only_uncovered_position(case in puzzle()) {
+        for case in case.symmetries() {
+            for solver in solvers::<i32>() {
+                prop_assert_eq!(
+                    solver.solve(&case.sensors, case.dimension),
+                    Some(case.expected_pos),
+                    "{} failed on:\n{}",
+                    solver.name(),
+                    const_test_case_source(&case)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn column_skipping_agrees_with_brute_force_in_every_metric(case in layout()) {
+        for case in case.symmetries() {
+            check_metric(Manhattan, &case)?;
+            check_metric(Chebyshev, &case)?;
+            // Euclidean ranges are squared, so also check with the ranges squared, for larger discs
+            check_metric(Euclidean, &case)?;
+            let squared = TestCase {
+                sensors: case
+                    .sensors
+                    .iter()
+                    .map(|sensor| Sensor {
+                        range: sensor.range * sensor.range,
+                        ..sensor.clone()
+                    })
+                    .collect(),
+                ..case
+            };
+            check_metric(Euclidean, &squared)?;
+        }
+    }
+
+    #[test]
+    fn slices_agree_with_brute_force_in_3d((sensors, dimension) in layout_3d()) {
+        let expected = three_d::brute_force(&sensors, dimension, usize::MAX);
+        for solver in solvers::<i32>() {
+            match three_d::solve_in_slices(solver, &sensors, dimension) {
+                Some(pos) => prop_assert!(expected.contains(&pos), "{}: {pos:?}", solver.name()),
+                None => prop_assert!(expected.is_empty(), "{}: None", solver.name()),
+            }
+        }
+    }
+
+    #[test]
+    fn solvers_agree_with_brute_force(case in layout()) {
+        for case in case.symmetries() {
+            for solver in solvers::<i32>() {
+                check_uncovered(solver, &case)?;
+            }
+        }
+    }
+}
@@ -1,42 +1,74 @@
 use std::ops::Range;
 
-use crate::{Aabb, Sensor, Vec2};
+use aoc_common::interval::{RangeOps, RangeSet};
 
-/// Solve by excluding ranges of coordinates that cannot be the solution.
-///
-/// Warning: This function does not behave correctly for inputs with no solution, or multiple
-/// solutions
-pub fn solve(sensors_diag: &mut Vec<Aabb>, dimension: i32) -> (Vec2, i64) {
+use crate::{
+    solver::{Part2Solver, Stats},
+    Aabb, Coord, Sensor, Vec2,
+};
+
+/// Excludes ranges of coordinates in diagonal space, where each sensor's range is an [`Aabb`].
+pub struct RangeExclusion;
+
+impl<T: Coord> Part2Solver<T> for RangeExclusion {
+    fn name(&self) -> &'static str {
+        "Range Exclusion"
+    }
+
+    fn solve_with_stats(&self, sensors: &[Sensor<T>], search_size: T) -> (Option<Vec2<T>>, Stats) {
+        let mut sensors_diag: Vec<Aabb<T>> = sensors
+            .iter()
+            .map(|s| aabb_from_sensor(s, search_size))
+            .collect();
+        let mut stats = Stats::default();
+        let pos = search(&mut sensors_diag, search_size, &mut stats);
+        (pos, stats)
+    }
+}
+
+/// Solve by excluding ranges of coordinates that cannot be the solution. Returns `None` if there
+/// is no uncovered position in the search area, or any one of them if there are several.
+pub fn solve<T: Coord>(sensors_diag: &mut [Aabb<T>], dimension: T) -> Option<Vec2<T>> {
+    search(sensors_diag, dimension, &mut Stats::default())
+}
+
+fn search<T: Coord>(
+    sensors_diag: &mut [Aabb<T>],
+    dimension: T,
+    stats: &mut Stats,
+) -> Option<Vec2<T>> {
     sensors_diag.sort_by_key(|s| s.x.start);
     let sensors_xdiag_ordered = sensors_diag;
 
-    let possible_x_diag_coords = exclude_x_diag_axis(sensors_xdiag_ordered, dimension);
-    let possible_y_diag_coords = exclude_y_diag_axis(
-        sensors_xdiag_ordered.iter(),
-        possible_x_diag_coords.ranges[0].start,
-        dimension,
-    );
-
-    assert_eq!(possible_x_diag_coords.ranges.len(), 1);
-    assert_eq!(possible_y_diag_coords.ranges.len(), 1);
-    // We may may get up to 3 candidate solutions in diagonal space, however only 1 of them will
-    // correspond to an integer point in rectangular space. Find that one.
-    for x_diag in possible_x_diag_coords.ranges[0].clone() {
-        for y_diag in possible_y_diag_coords.ranges[0].clone() {
-            if ((x_diag + y_diag) % 2) == (dimension % 2) {
-                let result_pos = diagonal_to_rectangular(
+    // Usually only one x_diag coordinate remains. If there are several uncovered positions, or
+    // combinations of sensors fail to exclude a coordinate, check each remaining one in turn.
+    let possible_x_diag_coords = exclude_x_diag_axis(sensors_xdiag_ordered, dimension, stats);
+    for x_diag in possible_x_diag_coords.values() {
+        let possible_y_diag_coords =
+            exclude_y_diag_axis(sensors_xdiag_ordered.iter(), x_diag, dimension, stats);
+        // Only coordinates with the right parity correspond to an integer point in rectangular
+        // space.
+        for y_diag in possible_y_diag_coords.values() {
+            stats.cells_probed += 1;
+            if ((x_diag + y_diag) % T::TWO) == (dimension % T::TWO) {
+                return Some(diagonal_to_rectangular(
                     &Vec2 {
                         x: x_diag,
                         y: y_diag,
                     },
                     dimension,
-                );
-                let result = result_pos.x as i64 * dimension as i64 + result_pos.y as i64;
-                return (result_pos, result);
+                ));
             }
         }
     }
-    panic!("No solution found");
+    None
+}
+
+#[derive(Debug, Clone)]
+struct Chain<T> {
+    x_axis: Range<T>,
+    y_axis: Range<T>,
+    sensors_idxs: Vec<usize>,
 }
 
 /// Exclude ranges of coordinates in the x_diag axis that cannot be the solution. Combinations
@@ -44,17 +76,12 @@ pub fn solve(sensors_diag: &mut Vec<Aabb>, dimension: i32) -> (Vec2, i64) {
 ///
 /// Preconditions:
 /// - Sensors must be sorted by x_diag_range.start
-/// - There must be at least 1 sensor.
-fn exclude_x_diag_axis(sensors: &Vec<Aabb>, dimension: i32) -> RangeSet {
-    #[derive(Debug, Clone)]
-    struct Chain {
-        x_axis: Range<i32>,
-        y_axis: Range<i32>,
-        sensors_idxs: Vec<usize>,
-    }
-
+fn exclude_x_diag_axis<T: Coord>(
+    sensors: &[Aabb<T>],
+    dimension: T,
+    stats: &mut Stats,
+) -> RangeSet<T> {
     // Assert preconditions
-    assert!(!sensors.is_empty(), "There must be at least 1 sensor");
     for i in 1..sensors.len() {
         assert!(
             sensors[i - 1].x.start <= sensors[i].x.start,
@@ -63,8 +90,8 @@ fn exclude_x_diag_axis(sensors: &Vec<Aabb>, dimension: i32) -> RangeSet {
     }
 
     // Initialize main loop
-    let dimension_diag = dimension * 2;
-    let mut to_visit: Vec<Chain> = sensors
+    let dimension_diag = dimension * T::TWO;
+    let mut to_visit: Vec<Chain<T>> = sensors
         .iter()
         .enumerate()
         .map(|(i, s)| Chain {
@@ -74,30 +101,34 @@ fn exclude_x_diag_axis(sensors: &Vec<Aabb>, dimension: i32) -> RangeSet {
         })
         .collect();
     let mut range_set = RangeSet::new();
-    range_set.add_range(&(0..(dimension_diag + 1)));
+    range_set.add_range(&(T::ZERO..(dimension_diag + T::ONE)));
 
     // Main loop
     while let Some(current) = to_visit.pop() {
+        stats.chains_explored += 1;
         // ===== Update range set based on the current chain ===== //
         // range from center of y_diag axis that is excluded
-        let y_diag_exclude_range = (dimension_diag / 2 - current.y_axis.start)
-            .min(current.y_axis.end - 1 - dimension_diag / 2);
-        let x_diag_exclude_lower = (0..y_diag_exclude_range + 1).intersection(&current.x_axis);
-        let x_diag_exclude_upper = ((dimension_diag - y_diag_exclude_range)..(dimension_diag + 1))
+        let y_diag_exclude_range = (dimension_diag / T::TWO - current.y_axis.start)
+            .min(current.y_axis.end - T::ONE - dimension_diag / T::TWO);
+        let x_diag_exclude_lower =
+            (T::ZERO..y_diag_exclude_range + T::ONE).intersection(&current.x_axis);
+        let x_diag_exclude_upper = ((dimension_diag - y_diag_exclude_range)
+            ..(dimension_diag + T::ONE))
             .intersection(&current.x_axis);
-        range_set.subtract_range(&x_diag_exclude_lower);
-        range_set.subtract_range(&x_diag_exclude_upper);
+        subtract_range(&mut range_set, &x_diag_exclude_lower, stats);
+        subtract_range(&mut range_set, &x_diag_exclude_upper, stats);
 
         // ===== Add new chains to visit ===== //
         let current_first_sensor_idx = current.sensors_idxs.first().unwrap();
         for (adjacent_candidate_idx, adjacent_candidate) in
             ((current_first_sensor_idx + 1)..sensors.len()).map(|i| (i, &sensors[i]))
         {
+            stats.sensors_checked += 1;
             let y_diag_axis_union = adjacent_candidate.y.union(&current.y_axis);
             assert!(y_diag_axis_union.size() >= current.y_axis.size());
             let x_diag_axis_intersection = adjacent_candidate.x.intersection(&current.x_axis);
             assert!(x_diag_axis_intersection.size() <= current.x_axis.size());
-            if x_diag_axis_intersection.size() <= 0 {
+            if x_diag_axis_intersection.size() <= T::ZERO {
                 if adjacent_candidate.x.start > current.x_axis.end {
                     // Adjacent candidate starts after current ends in xdiag axis. Adjacent
                     // candidates are ordered so all upcoming candidates will also have no
@@ -108,7 +139,7 @@ fn exclude_x_diag_axis(sensors: &Vec<Aabb>, dimension: i32) -> RangeSet {
                     continue;
                 }
             } else if y_diag_axis_union.size() == current.y_axis.size()
-                || adjacent_candidate.y.intersection(&current.y_axis).size() < 0
+                || adjacent_candidate.y.intersection(&current.y_axis).size() < T::ZERO
             {
                 // No ydiag axis overlap.
                 continue;
@@ -132,221 +163,67 @@ fn exclude_x_diag_axis(sensors: &Vec<Aabb>, dimension: i32) -> RangeSet {
 ///
 /// Preconditions:
 /// - Sensors must be sorted by x_diag_range.start
-fn exclude_y_diag_axis<'a, I: Iterator<Item = &'a Aabb>>(
+fn exclude_y_diag_axis<'a, T: Coord + 'a, I: Iterator<Item = &'a Aabb<T>>>(
     sensors: I,
-    x_diag_coord: i32,
-    dimension: i32,
-) -> RangeSet {
+    x_diag_coord: T,
+    dimension: T,
+    stats: &mut Stats,
+) -> RangeSet<T> {
     // Init main loop
     let mut result = RangeSet::new();
     let diag_midpoint = dimension;
     let x_diag_distance_from_midpoint = (x_diag_coord - diag_midpoint).abs();
     let y_diag_lower_bound = x_diag_distance_from_midpoint;
-    let y_diag_upper_bound = dimension * 2 - x_diag_distance_from_midpoint;
-    result.add_range(&(y_diag_lower_bound..y_diag_upper_bound + 1));
+    let y_diag_upper_bound = dimension * T::TWO - x_diag_distance_from_midpoint;
+    result.add_range(&(y_diag_lower_bound..y_diag_upper_bound + T::ONE));
 
     // Main loop
     for sensor in sensors {
+        stats.sensors_checked += 1;
         if sensor.x.contains(&x_diag_coord) {
             let range_to_sub = sensor.y.clone();
-            result.subtract_range(&range_to_sub);
+            subtract_range(&mut result, &range_to_sub, stats);
         }
     }
     result
 }
 
-fn rectangular_to_diagonal(vec: &Vec2, dimension: i32) -> Vec2 {
-    Vec2 {
-        x: vec.x - vec.y + dimension,
-        y: vec.x + vec.y,
+/// Subtracts `range` from `range_set`, counting it in `stats` if it splits one of the ranges in
+/// the set in two.
+fn subtract_range<T: Coord>(range_set: &mut RangeSet<T>, range: &Range<T>, stats: &mut Stats) {
+    let ranges = range_set.ranges().len();
+    range_set.subtract_range(range);
+    if range_set.ranges().len() > ranges {
+        stats.ranges_split += 1;
     }
 }
 
-fn diagonal_to_rectangular(vec: &Vec2, dimension: i32) -> Vec2 {
+pub(crate) fn rectangular_to_diagonal<T: Coord>(vec: &Vec2<T>, dimension: T) -> Vec2<T> {
     Vec2 {
-        x: (-dimension + vec.x + vec.y) / 2,
-        y: (dimension - vec.x + vec.y) / 2,
-    }
-}
-
-pub trait RangeOps {
-    fn overlaps(&self, other: &Self) -> bool;
-    fn touches(&self, other: &Self) -> bool;
-    fn after(&self, other: &Self) -> bool;
-    fn before(&self, other: &Self) -> bool {
-        other.after(self)
-    }
-    fn truncate_after(&self, truncate_at: i32) -> Self;
-    fn truncate_before(&self, truncate_at: i32) -> Self;
-    fn intersection(&self, other: &Self) -> Self;
-    fn union(&self, other: &Self) -> Self;
-    fn size(&self) -> i32;
-}
-
-impl RangeOps for std::ops::Range<i32> {
-    fn overlaps(&self, other: &std::ops::Range<i32>) -> bool {
-        self.start < other.end && self.end > other.start
-    }
-
-    fn touches(&self, other: &std::ops::Range<i32>) -> bool {
-        self.start <= other.end && self.end >= other.start
-    }
-
-    fn after(&self, other: &std::ops::Range<i32>) -> bool {
-        self.start >= other.end
-    }
-
-    fn truncate_after(&self, truncate_at_exclusive: i32) -> Range<i32> {
-        Range {
-            start: self.start,
-            end: self.end.min(truncate_at_exclusive),
-        }
-    }
-
-    fn truncate_before(&self, truncate_at_inclusive: i32) -> Range<i32> {
-        Range {
-            start: self.start.max(truncate_at_inclusive),
-            end: self.end,
-        }
-    }
-
-    fn intersection(&self, other: &std::ops::Range<i32>) -> Range<i32> {
-        Range {
-            start: self.start.max(other.start),
-            end: self.end.min(other.end),
-        }
-    }
-
-    fn union(&self, other: &std::ops::Range<i32>) -> Range<i32> {
-        Range {
-            start: self.start.min(other.start),
-            end: self.end.max(other.end),
-        }
-    }
-
-    fn size(&self) -> i32 {
-        self.end - self.start
-    }
-}
-
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct RangeSet {
-    pub ranges: Vec<Range<i32>>,
-}
-
-impl Default for RangeSet {
-    fn default() -> Self {
-        Self::new()
+        x: vec.x - vec.y + dimension,
+        y: vec.x + vec.y,
     }
 }
 
-impl RangeSet {
-    pub fn new() -> Self {
-        RangeSet { ranges: Vec::new() }
-    }
-
-    pub fn add_range(&mut self, range_to_add: &Range<i32>) {
-        if range_to_add.is_empty() {
-            return;
-        }
-        // Find all existing ranges which overlap with `range`
-        let mut existing_ranges = self.ranges.iter().enumerate();
-        // First iterate to first touching range
-        let mut touching_range_start_idx = None;
-        let mut insert_at_idx = 0;
-        for (i, range) in existing_ranges.by_ref() {
-            insert_at_idx = i;
-            if range.touches(range_to_add) {
-                // Found the forst touching range
-                touching_range_start_idx = Some(i);
-                insert_at_idx = i;
-                break;
-            } else if range_to_add.after(range) {
-                // Found a that comes after `range_to_insert` and does not touch it. This means
-                // that all of the subsequent ranges will also not touch and we can break early
-                insert_at_idx = i + 1;
-                break;
-            }
-        }
-        match touching_range_start_idx {
-            None => {
-                // There are no touching ranges. Add this range to the list
-                self.ranges.insert(insert_at_idx, range_to_add.clone());
-            }
-            Some(touching_range_start_idx) => {
-                let mut touching_range_end_idx = touching_range_start_idx + 1;
-                for (i, range) in existing_ranges {
-                    if range.touches(range_to_add) {
-                        touching_range_end_idx = i + 1;
-                    } else {
-                        break;
-                    }
-                }
-                let merged = Range {
-                    start: self.ranges[touching_range_start_idx].start,
-                    end: self.ranges[touching_range_end_idx - 1].end,
-                };
-                self.ranges
-                    .splice(touching_range_start_idx..touching_range_end_idx, [merged]);
-            }
-        }
-    }
-
-    pub fn subtract_range(&mut self, range_to_subtract: &Range<i32>) {
-        if range_to_subtract.is_empty() {
-            return;
-        }
-        // Find range before and after range_to_subtract
-        let mut before_idx = -1;
-        let mut after_idx = self.ranges.len() as i32;
-        for (i, range) in self.ranges.iter().enumerate() {
-            if range.before(range_to_subtract) {
-                before_idx = i as i32;
-            }
-            if range.after(range_to_subtract) {
-                after_idx = after_idx.min(i as i32);
-            }
-        }
-
-        let mut replacements = Vec::new();
-        let left_range_to_truncate_idx = before_idx + 1;
-        if left_range_to_truncate_idx >= 0 && left_range_to_truncate_idx < self.ranges.len() as i32
-        {
-            let left_replacement = self.ranges[left_range_to_truncate_idx as usize]
-                .truncate_after(range_to_subtract.start);
-            if !left_replacement.is_empty() {
-                replacements.push(left_replacement);
-            }
-        }
-        let right_range_to_truncate_idx = (after_idx - 1) as usize;
-        if right_range_to_truncate_idx < self.ranges.len() {
-            let right_replacement =
-                self.ranges[right_range_to_truncate_idx].truncate_before(range_to_subtract.end);
-            if !right_replacement.is_empty() {
-                replacements.push(right_replacement);
-            }
-        }
-        self.ranges
-            .splice((before_idx + 1) as usize..after_idx as usize, replacements);
+fn diagonal_to_rectangular<T: Coord>(vec: &Vec2<T>, dimension: T) -> Vec2<T> {
+    Vec2 {
+        x: (-dimension + vec.x + vec.y) / T::TWO,
+        y: (dimension - vec.x + vec.y) / T::TWO,
     }
 }
 
-pub fn aabb_from_sensor(s: &Sensor, dimension: i32) -> Aabb {
+pub fn aabb_from_sensor<T: Coord>(s: &Sensor<T>, dimension: T) -> Aabb<T> {
     let diag_pos = rectangular_to_diagonal(&s.pos, dimension);
     Aabb {
-        x: diag_pos.x - s.range..diag_pos.x + s.range + 1,
-        y: diag_pos.y - s.range..diag_pos.y + s.range + 1,
+        x: diag_pos.x - s.range..diag_pos.x + s.range + T::ONE,
+        y: diag_pos.y - s.range..diag_pos.y + s.range + T::ONE,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        range_exclusion::{
-            aabb_from_sensor, diagonal_to_rectangular, rectangular_to_diagonal, solve, RangeOps,
-            RangeSet,
-        },
-        test_case::{self, TestCase},
+        range_exclusion::{diagonal_to_rectangular, rectangular_to_diagonal},
         Vec2,
     };
 
@@ -409,195 +286,4 @@ mod tests {
             );
         }
     }
-
-    fn test_case<T>(test_case: T)
-    where
-        TestCase: From<T>,
-    {
-        let mut test_case = TestCase::from(test_case);
-        // Test each cast with 4 rotations
-        for _ in 0..4 {
-            let mut sensors = test_case
-                .sensors
-                .iter()
-                .map(|s| aabb_from_sensor(s, test_case.dimension))
-                .collect();
-            let result = solve(&mut sensors, test_case.dimension);
-            assert_eq!(&result.0, &test_case.expected_pos);
-            assert_eq!(result.1, test_case.expected_answer());
-            test_case = test_case.rotated();
-        }
-    }
-
-    #[test]
-    fn const_test_cases() {
-        for case in test_case::CONST_TEST_CASES {
-            test_case(case);
-        }
-    }
-
-    #[test]
-    fn file_test_cases() {
-        for case in test_case::FILE_TEST_CASES {
-            test_case(case);
-        }
-    }
-
-    #[test]
-    fn test_range() {
-        assert!((3..4).after(&(2..3)));
-        assert!((2..3).before(&(3..4)));
-        assert!(!(2..3).overlaps(&(3..4)));
-        assert!((2..3).overlaps(&(2..4)));
-        assert!((2..3).touches(&(3..4)));
-        assert!(!(2..3).touches(&(4..5)));
-        assert!(!(-100..-51).before(&(-75..4)));
-        assert!(!(-75..4).after(&(-100..-51)));
-        assert_eq!((0..100).truncate_after(50), 0..50);
-        assert_eq!((0..100).truncate_before(50), 50..100);
-        assert_eq!((0..100).truncate_after(100), 0..100);
-        assert_eq!((0..100).truncate_before(0), 0..100);
-        assert!((0..100).truncate_after(-100).is_empty());
-        dbg!((0..100).truncate_after(-100));
-        assert!((0..100).truncate_before(200).is_empty());
-    }
-
-    #[test]
-    fn test_range_set() {
-        // Test empty RangeSet
-        let mut range_set = RangeSet::new();
-        assert_eq!(range_set, RangeSet { ranges: vec![] });
-
-        // Test adding first range
-        range_set.add_range(&(2..3));
-        assert_eq!(range_set, RangeSet { ranges: vec![2..3] });
-
-        // Test adding non touching range at end
-        range_set.add_range(&(4..5));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![2..3, 4..5]
-            }
-        );
-
-        // Test adding range which causes all existing ranges to be merged
-        range_set.add_range(&(3..4));
-        assert_eq!(range_set, RangeSet { ranges: vec![2..5] });
-
-        // Test adding redundant range
-        range_set.add_range(&(2..4));
-        assert_eq!(range_set, RangeSet { ranges: vec![2..5] });
-
-        // Test adding redundant empty range
-        range_set.add_range(&(10..-10));
-        assert_eq!(range_set, RangeSet { ranges: vec![2..5] });
-
-        // Test adding non touching range at start
-        range_set.add_range(&(-100..-51));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![-100..-51, 2..5],
-            }
-        );
-
-        // Test adding non touching range in middle
-        range_set.add_range(&(-20..-15));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![-100..-51, -20..-15, 2..5],
-            }
-        );
-
-        // Test redundant subtraction after all existing groups
-        range_set.subtract_range(&(100..110));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![-100..-51, -20..-15, 2..5],
-            }
-        );
-
-        // Test redundant subtraction before all existing groups
-        range_set.subtract_range(&(-1000..-999));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![-100..-51, -20..-15, 2..5],
-            }
-        );
-
-        // Test redundant subtraction of range between existing ranges
-        range_set.subtract_range(&(0..1));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![-100..-51, -20..-15, 2..5],
-            }
-        );
-
-        // Test redundant subtraction of empty range
-        range_set.subtract_range(&(10..-10));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![-100..-51, -20..-15, 2..5],
-            }
-        );
-
-        // Test subtraction which truncates a left and right range, and removes a range in the middle
-        range_set.subtract_range(&(-75..4));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![-100..-75, 4..5],
-            }
-        );
-
-        // Test subtraction in the middle of a range
-        range_set.subtract_range(&(-90..-85));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![-100..-90, -85..-75, 4..5],
-            }
-        );
-
-        // Test subtraction which removes a range in the middle
-        range_set.subtract_range(&(-85..-75));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![-100..-90, 4..5],
-            }
-        );
-
-        // Test subtraction which truncates left range only
-        range_set.subtract_range(&(-200..-95));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![-95..-90, 4..5],
-            }
-        );
-
-        // Test subtraction which removes right range
-        range_set.subtract_range(&(0..100));
-        assert_eq!(
-            range_set,
-            RangeSet {
-                ranges: vec![-95..-90],
-            }
-        );
-
-        // Test subtraction which removes last range
-        range_set.subtract_range(&(-1000..1000));
-        assert_eq!(range_set, RangeSet { ranges: vec![] });
-
-        // Test redundant subtraction on empty range set
-        range_set.subtract_range(&(-1000..1000));
-        assert_eq!(range_set, RangeSet { ranges: vec![] });
-    }
 }
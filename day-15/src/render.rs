@@ -0,0 +1,215 @@
+//! Images of the sensors' coverage, for looking at layouts too large for [`crate::draw_map`].
+//!
+//! [`svg`] draws each sensor's range as a diamond, over the search area, with the distress beacon
+//! marked by a crosshair. It scales to any search size, and hovering over a diamond shows which
+//! sensor it belongs to. [`heat_map`] samples the search area at a fixed resolution, colouring
+//! each pixel by the number of sensors covering it, which shows where ranges overlap heavily.
+
+use std::fmt::Write;
+
+use crate::{test_case::TestCase, Vec2};
+
+/// Width of the SVG as displayed, in pixels. The height follows from the aspect ratio.
+const SVG_WIDTH: f64 = 800.0;
+
+/// Colour of the pixels covered by a single sensor in the heat map, and by the most sensors.
+const FEW_SENSORS: [u8; 3] = [20, 40, 120];
+const MANY_SENSORS: [u8; 3] = [250, 230, 80];
+/// Colour of the marker around the distress beacon in the heat map.
+const MARKER: [u8; 3] = [230, 30, 30];
+
+/// SVG of the sensors' ranges, the search area and the expected position of the distress beacon.
+/// Each position is drawn as a unit square centred on its coordinates.
+pub fn svg(test_case: &TestCase) -> String {
+    let (min, max) = bounds(test_case);
+    let margin = ((max.x - min.x).max(max.y - min.y) as f64 / 50.0).max(1.0);
+    let view = (
+        min.x as f64 - 0.5 - margin,
+        min.y as f64 - 0.5 - margin,
+        (max.x - min.x) as f64 + 1.0 + 2.0 * margin,
+        (max.y - min.y) as f64 + 1.0 + 2.0 * margin,
+    );
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{SVG_WIDTH}" height="{}">"#,
+        view.0,
+        view.1,
+        view.2,
+        view.3,
+        (SVG_WIDTH * view.3 / view.2).round()
+    )
+    .unwrap();
+    let size = test_case.dimension as f64 + 1.0;
+    writeln!(
+        svg,
+        r#"  <rect x="-0.5" y="-0.5" width="{size}" height="{size}" fill="none" stroke="black" stroke-width="2" stroke-dasharray="6 4" vector-effect="non-scaling-stroke"/>"#
+    )
+    .unwrap();
+    for sensor in &test_case.sensors {
+        let (x, y, r) = (
+            sensor.pos.x as f64,
+            sensor.pos.y as f64,
+            sensor.range as f64 + 0.5,
+        );
+        writeln!(
+            svg,
+            r#"  <polygon points="{x},{} {},{y} {x},{} {},{y}" fill="steelblue" fill-opacity="0.3" stroke="navy" vector-effect="non-scaling-stroke"><title>Sensor {} at x={}, y={}: range {}</title></polygon>"#,
+            y - r,
+            x + r,
+            y + r,
+            x - r,
+            sensor.id(),
+            sensor.pos.x,
+            sensor.pos.y,
+            sensor.range
+        )
+        .unwrap();
+    }
+    // The distress beacon is a single position, which is too small to see in a large search
+    // area, so it is marked by lines across the whole image.
+    let Vec2 { x, y } = test_case.expected_pos;
+    writeln!(
+        svg,
+        r#"  <g stroke="red" vector-effect="non-scaling-stroke"><title>Distress beacon at x={x}, y={y}</title><line x1="{x}" y1="{}" x2="{x}" y2="{}" vector-effect="non-scaling-stroke"/><line x1="{}" y1="{y}" x2="{}" y2="{y}" vector-effect="non-scaling-stroke"/><rect x="{}" y="{}" width="1" height="1" fill="red"/></g>"#,
+        view.1,
+        view.1 + view.3,
+        view.0,
+        view.0 + view.2,
+        x as f64 - 0.5,
+        y as f64 - 0.5
+    )
+    .unwrap();
+    svg += "</svg>\n";
+    svg
+}
+
+/// PNG of the number of sensors covering each position in the search area, at most `max_side`
+/// pixels wide and high. Larger search areas are downsampled, with each pixel showing the
+/// position at the centre of the block of positions it stands for.
+///
+/// Uncovered positions are black, and covered ones range from dark blue to yellow as more
+/// sensors cover them. The distress beacon is surrounded by a red cross, which leaves its own
+/// pixel uncovered.
+pub fn heat_map(test_case: &TestCase, max_side: u32) -> Vec<u8> {
+    let cells = test_case.dimension as i64 + 1;
+    let side = cells.min(max_side.max(1) as i64);
+    let cell = |pixel: i64| ((2 * pixel + 1) * cells / (2 * side)) as i32;
+
+    let mut counts = Vec::with_capacity((side * side) as usize);
+    for py in 0..side {
+        for px in 0..side {
+            let pos = Vec2::new(cell(px), cell(py));
+            counts.push(test_case.sensors.iter().filter(|s| s.covers(pos)).count());
+        }
+    }
+    let most = counts.iter().copied().max().unwrap_or(0);
+    let mut pixels: Vec<[u8; 3]> = counts
+        .into_iter()
+        .map(|count| match count {
+            0 => [0, 0, 0],
+            _ if most == 1 => FEW_SENSORS,
+            _ => {
+                let t = (count - 1) as f64 / (most - 1) as f64;
+                [0, 1, 2].map(|channel| {
+                    let (few, many) = (FEW_SENSORS[channel] as f64, MANY_SENSORS[channel] as f64);
+                    (few + (many - few) * t).round() as u8
+                })
+            }
+        })
+        .collect();
+
+    let to_pixel = |coord: i32| coord as i64 * side / cells;
+    let (bx, by) = (
+        to_pixel(test_case.expected_pos.x),
+        to_pixel(test_case.expected_pos.y),
+    );
+    let arm = (side / 50).max(2);
+    for offset in (-arm..=arm).filter(|&offset| offset != 0) {
+        for (px, py) in [(bx + offset, by), (bx, by + offset)] {
+            if (0..side).contains(&px) && (0..side).contains(&py) {
+                pixels[(py * side + px) as usize] = MARKER;
+            }
+        }
+    }
+
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, side as u32, side as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().expect("writing to a Vec can't fail");
+    writer
+        .write_image_data(pixels.as_flattened())
+        .expect("the image data matches the header");
+    writer.finish().expect("writing to a Vec can't fail");
+    png
+}
+
+/// Corners of the smallest rectangle containing the search area and every sensor's range.
+fn bounds(test_case: &TestCase) -> (Vec2, Vec2) {
+    let mut min = Vec2::new(0, 0);
+    let mut max = Vec2::new(test_case.dimension, test_case.dimension);
+    for sensor in &test_case.sensors {
+        min.x = min.x.min(sensor.pos.x - sensor.range);
+        min.y = min.y.min(sensor.pos.y - sensor.range);
+        max.x = max.x.max(sensor.pos.x + sensor.range);
+        max.y = max.y.max(sensor.pos.y + sensor.range);
+    }
+    (min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{heat_map, svg, MARKER};
+    use crate::test_case::{self, TestCase};
+
+    /// Decodes a PNG written by [`heat_map`] into its side length and RGB pixels.
+    fn decode(png: &[u8]) -> (u32, Vec<[u8; 3]>) {
+        let mut reader = png::Decoder::new(png).read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+        assert_eq!(info.width, info.height);
+        let pixels = data[..info.buffer_size()]
+            .chunks(3)
+            .map(|rgb| [rgb[0], rgb[1], rgb[2]])
+            .collect();
+        (info.width, pixels)
+    }
+
+    #[test]
+    fn example_svg() {
+        let example = TestCase::from(&test_case::AOC_EXAMPLE);
+        let svg = svg(&example);
+        assert!(svg.starts_with("<svg ") && svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<polygon").count(), example.sensors.len());
+        // The first sensor is on line 2, after the header
+        assert!(svg.contains(
+            r#"<polygon points="2,10.5 9.5,18 2,25.5 -5.5,18" fill="steelblue" fill-opacity="0.3" stroke="navy" vector-effect="non-scaling-stroke"><title>Sensor 2 at x=2, y=18: range 7</title></polygon>"#
+        ));
+        assert!(svg.contains(r#"<rect x="-0.5" y="-0.5" width="21" height="21""#));
+        assert!(svg.contains("<title>Distress beacon at x=14, y=11</title>"));
+    }
+
+    #[test]
+    fn example_heat_map() {
+        let example = TestCase::from(&test_case::AOC_EXAMPLE);
+        let (side, pixels) = decode(&heat_map(&example, 100));
+        assert_eq!(side, 21);
+        let uncovered: Vec<usize> = (0..pixels.len())
+            .filter(|&idx| pixels[idx] == [0, 0, 0])
+            .collect();
+        assert_eq!(uncovered, vec![11 * 21 + 14]);
+        assert_eq!(pixels[11 * 21 + 13], MARKER);
+        assert_eq!(pixels[12 * 21 + 14], MARKER);
+    }
+
+    #[test]
+    fn downsampled_heat_map() {
+        let actual = TestCase::from(&test_case::AOC_ACTUAL);
+        let (side, pixels) = decode(&heat_map(&actual, 64));
+        assert_eq!(side, 64);
+        assert!(pixels.iter().all(|&pixel| pixel != [0, 0, 0]));
+        assert_eq!(pixels.iter().filter(|&&pixel| pixel == MARKER).count(), 8);
+    }
+}
@@ -0,0 +1,344 @@
+//! Common interface to the part 2 algorithms, so they can be tested, benchmarked and run
+//! interchangeably.
+
+use std::{fmt, ops::AddAssign};
+
+use crate::{
+    border_intersection::BorderIntersection, brute_force::BruteForce,
+    column_skipping::ColumnSkipping, range_exclusion::RangeExclusion, sweep_line::SweepLine,
+    tuning_frequency, Coord, Day15, Sensor, Vec2,
+};
+
+/// Algorithm which finds positions in the search area that aren't covered by any sensor. The
+/// search area is the square from `(0, 0)` to `(search_size, search_size)` inclusive.
+///
+/// Puzzle inputs have exactly one uncovered position, but the solvers also handle sensor layouts
+/// with none or several. Every solver is implemented for any [`Coord`] type, so larger search
+/// areas can use `i64` coordinates.
+pub trait Part2Solver<T: Coord = i32>: Sync {
+    /// Human readable name, used to label benchmarks and test failures.
+    fn name(&self) -> &'static str;
+
+    /// An uncovered position, or `None` if the whole search area is covered. If there are
+    /// several, which one is returned depends on the algorithm.
+    fn solve(&self, sensors: &[Sensor<T>], search_size: T) -> Option<Vec2<T>> {
+        self.solve_with_stats(sensors, search_size).0
+    }
+
+    /// Same as [`Part2Solver::solve`], along with counts of the work done to find the result.
+    fn solve_with_stats(&self, sensors: &[Sensor<T>], search_size: T) -> (Option<Vec2<T>>, Stats);
+
+    /// Uncovered positions, up to `limit` of them, in no particular order.
+    ///
+    /// By default, each position found by [`Part2Solver::solve`] is covered by a sensor with a
+    /// range of 0 before solving again, which is slow if there are many uncovered positions.
+    fn uncovered(&self, sensors: &[Sensor<T>], search_size: T, limit: usize) -> Vec<Vec2<T>> {
+        let mut sensors = sensors.to_vec();
+        let mut uncovered = Vec::new();
+        while uncovered.len() < limit {
+            let Some(pos) = self.solve(&sensors, search_size) else {
+                break;
+            };
+            uncovered.push(pos);
+            sensors.push(Sensor {
+                id: sensors.len(),
+                pos,
+                range: T::ZERO,
+            });
+        }
+        uncovered
+    }
+
+    /// Whether the algorithm finishes in a reasonable time for a search area of the given size.
+    fn is_practical(&self, _search_size: T) -> bool {
+        true
+    }
+}
+
+/// Counts of the work done by a solver, which explain why one algorithm is faster than another on
+/// the same input. Each solver only counts the kinds of work it does, and leaves the rest at 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Positions checked for being outside the range of every sensor.
+    pub cells_probed: u64,
+    /// Times a sensor's range was compared with a position, or used to include or exclude
+    /// coordinates in diagonal space.
+    pub sensors_checked: u64,
+    /// Pairs of border segments checked for an intersection.
+    pub segment_intersections: u64,
+    /// Chains of overlapping sensors visited while excluding x_diag coordinates.
+    pub chains_explored: u64,
+    /// Ranges of possible coordinates split in two by excluding the middle of them.
+    pub ranges_split: u64,
+}
+
+impl AddAssign for Stats {
+    fn add_assign(&mut self, other: Self) {
+        self.cells_probed += other.cells_probed;
+        self.sensors_checked += other.sensors_checked;
+        self.segment_intersections += other.segment_intersections;
+        self.chains_explored += other.chains_explored;
+        self.ranges_split += other.ranges_split;
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} cells probed, {} sensors checked, {} segment intersections tested, {} chains \
+             explored, {} ranges split",
+            self.cells_probed,
+            self.sensors_checked,
+            self.segment_intersections,
+            self.chains_explored,
+            self.ranges_split
+        )
+    }
+}
+
+/// Every part 2 algorithm, from slowest to fastest on the actual puzzle input, followed by the
+/// multi-threaded ones if the `parallel` feature is enabled.
+pub fn solvers<T: Coord>() -> Vec<&'static dyn Part2Solver<T>> {
+    #[allow(unused_mut)]
+    let mut solvers: Vec<&'static dyn Part2Solver<T>> = vec![
+        &BruteForce,
+        &ColumnSkipping,
+        &RangeExclusion,
+        &SweepLine,
+        &BorderIntersection,
+    ];
+    #[cfg(feature = "parallel")]
+    solvers.extend([
+        &crate::parallel::ParallelColumnSkipping as &dyn Part2Solver<T>,
+        &crate::parallel::ParallelBorderIntersection,
+    ]);
+    solvers
+}
+
+/// Solves part 2 of the puzzle input named on the command line using `solver`, and prints the
+/// position of the distress beacon, its tuning frequency and the work done to find it.
+pub fn run(solver: &dyn Part2Solver, default_path: &str) {
+    let puzzle = aoc_common::solution::parse_from_args::<Day15>(default_path);
+    let (pos, stats) = solver.solve_with_stats(&puzzle.sensors, puzzle.search_size);
+    match pos {
+        Some(pos) => {
+            println!("{}: {pos:?}", solver.name());
+            println!("Tuning frequency: {}", tuning_frequency(pos));
+        }
+        None => println!("{}: every position is covered", solver.name()),
+    }
+    println!("Work done: {stats}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solvers, Part2Solver, Stats};
+    use crate::{
+        border_intersection::BorderIntersection,
+        brute_force::{self, BruteForce},
+        column_skipping::ColumnSkipping,
+        generator,
+        range_exclusion::RangeExclusion,
+        sweep_line::SweepLine,
+        test_case::{self, TestCase},
+        Coord, Sensor, Vec2,
+    };
+
+    /// Solves `test_case` with every practical solver, in 4 rotations, with coordinates of type
+    /// `T`.
+    fn check<T: Coord + From<i32>>(test_case: TestCase) {
+        let mut test_case = test_case;
+        for rotation in 0..4 {
+            let sensors: Vec<Sensor<T>> = test_case.sensors.iter().map(Sensor::cast).collect();
+            let dimension = T::from(test_case.dimension);
+            let expected_pos = Vec2::new(
+                test_case.expected_pos.x.into(),
+                test_case.expected_pos.y.into(),
+            );
+            for solver in solvers::<T>() {
+                if !solver.is_practical(dimension) {
+                    continue;
+                }
+                assert_eq!(
+                    solver.solve(&sensors, dimension),
+                    Some(expected_pos),
+                    "{}: case {:?}, rotation {rotation}",
+                    solver.name(),
+                    test_case.name
+                );
+            }
+            test_case = test_case.rotated();
+        }
+    }
+
+    #[test]
+    fn const_test_cases() {
+        for case in test_case::CONST_TEST_CASES {
+            check::<i32>(case.into());
+            check::<i64>(case.into());
+        }
+    }
+
+    #[test]
+    fn file_test_cases() {
+        let cases = test_case::file_test_cases();
+        let names: Vec<&str> = cases.iter().map(|case| case.name.as_str()).collect();
+        assert!(names.contains(&"aoc_actual") && names.contains(&"minimal_transposed"));
+        assert!(!names.contains(&"medium"));
+        for case in cases {
+            check::<i32>(case);
+        }
+        check::<i64>((&test_case::AOC_EXAMPLE).into());
+    }
+
+    /// Checks every solver's uncovered positions against brute force on the files which aren't
+    /// test cases, and that every file is loaded one way or the other.
+    #[test]
+    fn open_file_layouts() {
+        let layouts = test_case::file_open_layouts();
+        let names: Vec<&str> = layouts.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["medium"]);
+        let files = std::fs::read_dir(test_case::TEST_CASES_DIR)
+            .unwrap()
+            .count();
+        assert_eq!(test_case::file_test_cases().len() + layouts.len(), files);
+
+        for (name, puzzle) in layouts {
+            let (sensors, size) = (&puzzle.sensors, puzzle.search_size);
+            let mut expected = brute_force::solve(sensors, size, usize::MAX);
+            expected.sort();
+            assert!(expected.len() > 1, "{name}");
+            for solver in solvers::<i32>() {
+                let mut uncovered = solver.uncovered(sensors, size, usize::MAX);
+                uncovered.sort();
+                assert_eq!(uncovered, expected, "{}: {name}", solver.name());
+            }
+        }
+    }
+
+    #[test]
+    fn generated_test_cases() {
+        for (search_size, sensor_count, seed) in [(20, 10, 0), (1000, 100, 1), (100_000, 200, 2)] {
+            let layout = generator::generate(search_size, sensor_count, seed).unwrap();
+            check::<i32>((&layout).into());
+        }
+        let layout = generator::generate(300_000_000, 50, 3).unwrap();
+        check::<i64>((&layout).into());
+    }
+
+    /// A sensor in each corner of a search area too large for `i32`, whose ranges reach just
+    /// short of `hole`, which is the only uncovered position.
+    #[test]
+    fn beyond_i32() {
+        let size = 6_000_000_000i64;
+        let corners = [(0, 0), (0, size), (size, 0), (size, size)].map(|(x, y)| Vec2 { x, y });
+        for hole in [
+            (1, 1),
+            (3_000_000_000, 3_000_000_000),
+            (1_234_567_891, 5_987_654_321),
+        ] {
+            let hole = Vec2 {
+                x: hole.0,
+                y: hole.1,
+            };
+            let sensors: Vec<Sensor<i64>> = corners
+                .iter()
+                .enumerate()
+                .map(|(id, &pos)| Sensor {
+                    id,
+                    pos,
+                    range: pos.manhattan_distance(hole) - 1,
+                })
+                .collect();
+            let mut practical = 0;
+            for solver in solvers::<i64>() {
+                if solver.is_practical(size) {
+                    assert_eq!(
+                        solver.solve(&sensors, size),
+                        Some(hole),
+                        "{}",
+                        solver.name()
+                    );
+                    practical += 1;
+                }
+            }
+            assert!(practical >= 2);
+        }
+    }
+
+    #[test]
+    fn stats() {
+        let example = TestCase::from(&test_case::AOC_EXAMPLE);
+        let stats = |solver: &dyn Part2Solver| {
+            let (pos, stats) = solver.solve_with_stats(&example.sensors, example.dimension);
+            assert_eq!(pos, Some(example.expected_pos), "{}", solver.name());
+            assert!(stats.cells_probed > 0, "{}", solver.name());
+            assert!(stats.sensors_checked > 0, "{}", solver.name());
+            stats
+        };
+        for solver in solvers() {
+            stats(solver);
+        }
+
+        // Brute force checks every position before the distress beacon, column by column
+        let brute_force = stats(&BruteForce);
+        assert_eq!(brute_force.cells_probed, 14 * 21 + 12);
+        assert!(stats(&ColumnSkipping).cells_probed < brute_force.cells_probed);
+        let border_intersection = stats(&BorderIntersection);
+        assert!(border_intersection.segment_intersections > 0);
+        assert_eq!(border_intersection.chains_explored, 0);
+        let range_exclusion = stats(&RangeExclusion);
+        assert!(range_exclusion.chains_explored >= example.sensors.len() as u64);
+        assert!(range_exclusion.ranges_split > 0);
+        assert_eq!(range_exclusion.segment_intersections, 0);
+        let sweep_line = stats(&SweepLine);
+        // Each range is added to and removed from the sweep at most once
+        assert!(sweep_line.sensors_checked <= 2 * example.sensors.len() as u64);
+
+        let mut total = brute_force;
+        total += range_exclusion;
+        assert_eq!(
+            total,
+            Stats {
+                cells_probed: brute_force.cells_probed + range_exclusion.cells_probed,
+                sensors_checked: brute_force.sensors_checked + range_exclusion.sensors_checked,
+                ..range_exclusion
+            }
+        );
+    }
+
+    #[test]
+    fn fully_covered() {
+        let sensors = [Sensor {
+            id: 0,
+            pos: Vec2 { x: 1, y: 1 },
+            range: 2,
+        }];
+        for solver in solvers() {
+            assert_eq!(solver.solve(&sensors, 2), None, "{}", solver.name());
+            assert!(solver.uncovered(&sensors, 2, 10).is_empty());
+        }
+    }
+
+    #[test]
+    fn several_uncovered() {
+        let sensors = [Sensor {
+            id: 0,
+            pos: Vec2 { x: 0, y: 0 },
+            range: 1,
+        }];
+        let expected = [(0, 2), (1, 1), (1, 2), (2, 0), (2, 1), (2, 2)].map(|(x, y)| Vec2 { x, y });
+        for solver in solvers() {
+            let pos = solver.solve(&sensors, 2);
+            assert!(expected.contains(&pos.unwrap()), "{}", solver.name());
+            let mut uncovered = solver.uncovered(&sensors, 2, 10);
+            uncovered.sort();
+            assert_eq!(uncovered, expected, "{}", solver.name());
+            let uncovered = solver.uncovered(&sensors, 2, 4);
+            assert_eq!(uncovered.len(), 4, "{}", solver.name());
+            assert!(uncovered.iter().all(|pos| expected.contains(pos)));
+            assert_eq!(solver.uncovered(&sensors, 2, 0), [], "{}", solver.name());
+        }
+    }
+}
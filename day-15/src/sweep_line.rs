@@ -0,0 +1,280 @@
+//! Sweeps a line across diagonal space (see
+//! [`rectangular_to_diagonal`](crate::range_exclusion::rectangular_to_diagonal)), where each
+//! sensor's range is an [`Aabb`] and the search area is a diamond.
+//!
+//! Each sensor's range adds an event where the line enters it and another where it leaves it.
+//! Between consecutive events, in a band of x_diag coordinates, the y_diag coordinates covered by
+//! the active sensors don't change. They are kept in a segment tree over the y_diag coordinates
+//! where ranges start or end, which finds the uncovered gaps in O(log n) per gap. Within a band,
+//! a gap is a rectangle, and only a few x_diag coordinates need to be checked for a position
+//! which is in the search area and isn't between two cells, so the whole sweep takes
+//! O(n log n), plus the cost of checking the gaps.
+
+use std::ops::Range;
+
+use crate::{
+    range_exclusion::aabb_from_sensor,
+    solver::{Part2Solver, Stats},
+    Aabb, Coord, Sensor, Vec2,
+};
+
+/// Sweeps across the sensors' ranges in diagonal space, checking the gaps between them.
+pub struct SweepLine;
+
+impl<T: Coord> Part2Solver<T> for SweepLine {
+    fn name(&self) -> &'static str {
+        "Sweep Line"
+    }
+
+    fn solve_with_stats(&self, sensors: &[Sensor<T>], search_size: T) -> (Option<Vec2<T>>, Stats) {
+        let mut stats = Stats::default();
+        let pos = search(sensors, search_size, &mut stats);
+        (pos, stats)
+    }
+}
+
+/// Returns `None` if there is no uncovered position in the search area, or any one of them if
+/// there are several.
+pub fn solve<T: Coord>(sensors: &[Sensor<T>], dimension: T) -> Option<Vec2<T>> {
+    search(sensors, dimension, &mut Stats::default())
+}
+
+fn search<T: Coord>(sensors: &[Sensor<T>], dimension: T, stats: &mut Stats) -> Option<Vec2<T>> {
+    if dimension < T::ZERO {
+        return None;
+    }
+    // Both diagonal coordinates of the search area are within 0..=2 * dimension
+    let end = T::TWO * dimension + T::ONE;
+    let clip = |coord: T| coord.max(T::ZERO).min(end);
+    let ranges: Vec<Aabb<T>> = sensors
+        .iter()
+        .map(|sensor| aabb_from_sensor(sensor, dimension))
+        .collect();
+
+    let mut ys: Vec<T> = ranges
+        .iter()
+        .flat_map(|range| [clip(range.y.start), clip(range.y.end)])
+        .chain([T::ZERO, end])
+        .collect();
+    ys.sort_unstable();
+    ys.dedup();
+    let y_index = |y: T| ys.partition_point(|&other| other < clip(y));
+
+    // (x_diag, index of the range, whether the line is entering it)
+    let mut events: Vec<(T, usize, bool)> = ranges
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, range)| {
+            [
+                (clip(range.x.start), idx, true),
+                (clip(range.x.end), idx, false),
+            ]
+        })
+        .collect();
+    events.sort_unstable_by_key(|&(x, _, _)| x);
+
+    let mut cover_counts = CoverCounts::new(ys.len() - 1);
+    let mut next_event = 0;
+    let mut band_start = T::ZERO;
+    while band_start < end {
+        while let Some(&(x, idx, entering)) = events.get(next_event) {
+            if x > band_start {
+                break;
+            }
+            stats.sensors_checked += 1;
+            let y = &ranges[idx].y;
+            let delta = if entering { 1 } else { -1 };
+            cover_counts.add(y_index(y.start)..y_index(y.end), delta);
+            next_event += 1;
+        }
+        let band_end = events.get(next_event).map_or(end, |&(x, _, _)| x);
+
+        let band = band_start.to_i128()..band_end.to_i128();
+        for gap in cover_counts.uncovered() {
+            let gap = ys[gap.start].to_i128()..ys[gap.end].to_i128();
+            if let Some(pos) = solve_in_gap(&band, &gap, dimension.to_i128(), stats) {
+                return Some(Vec2::new(
+                    T::from_i128(pos.x).expect("positions in the search area fit in T"),
+                    T::from_i128(pos.y).expect("positions in the search area fit in T"),
+                ));
+            }
+        }
+        band_start = band_end;
+    }
+    None
+}
+
+/// A position in the search area within the rectangle of diagonal space spanned by `x_diag` and
+/// `y_diag`, if there is one.
+///
+/// At each x_diag coordinate, the y_diag coordinates in both the gap and the search area form a
+/// range whose bounds are given by lines of slope -1, 0 or 1. The length of that range is largest
+/// where two of those lines cross, or at the edges of the band, and only every other y_diag
+/// coordinate is a cell, so it is enough to check around those x_diag coordinates.
+fn solve_in_gap(
+    x_diag: &Range<i128>,
+    y_diag: &Range<i128>,
+    dimension: i128,
+    stats: &mut Stats,
+) -> Option<Vec2<i128>> {
+    // Lines `slope * x_diag + offset` bounding the y_diag coordinates from below and above:
+    // the gap, then x >= 0, y >= 0 for the lower bounds, and x <= dimension, y <= dimension for
+    // the upper bounds.
+    let lower = [(0, y_diag.start), (-1, dimension), (1, -dimension)];
+    let upper = [(0, y_diag.end - 1), (-1, 3 * dimension), (1, dimension)];
+    let bounds = |x: i128| {
+        let bound = |(slope, offset): (i128, i128)| slope * x + offset;
+        (
+            lower.into_iter().map(bound).max().unwrap(),
+            upper.into_iter().map(bound).min().unwrap(),
+        )
+    };
+
+    let mut candidates = vec![
+        x_diag.start,
+        x_diag.start + 1,
+        x_diag.end - 2,
+        x_diag.end - 1,
+    ];
+    for (idx, &(slope_a, offset_a)) in lower.iter().chain(&upper).enumerate() {
+        for &(slope_b, offset_b) in lower.iter().chain(&upper).skip(idx + 1) {
+            if slope_a != slope_b {
+                let crossing = (offset_b - offset_a).div_euclid(slope_a - slope_b);
+                candidates.extend(crossing - 1..=crossing + 2);
+            }
+        }
+    }
+
+    candidates
+        .into_iter()
+        .filter(|x| x_diag.contains(x))
+        .find_map(|x| {
+            stats.cells_probed += 1;
+            let (min_y, max_y) = bounds(x);
+            // Cells are where x_diag - dimension and y_diag have the same parity
+            let y = min_y + (x - dimension - min_y).rem_euclid(2);
+            (y <= max_y).then(|| Vec2::new((x + y - dimension) / 2, (y - x + dimension) / 2))
+        })
+}
+
+/// Number of active ranges covering each of a sequence of intervals, as a segment tree. Ranges
+/// are always removed exactly as they were added, so counts are never negative.
+struct CoverCounts {
+    len: usize,
+    /// Count added to the whole of each node's intervals.
+    added: Vec<i32>,
+    /// Smallest count of any of each node's intervals, ignoring the counts added to its
+    /// ancestors.
+    min: Vec<i32>,
+}
+
+impl CoverCounts {
+    fn new(len: usize) -> Self {
+        Self {
+            len,
+            added: vec![0; 4 * len.max(1)],
+            min: vec![0; 4 * len.max(1)],
+        }
+    }
+
+    /// Adds `delta` to the count of each interval in `range`.
+    fn add(&mut self, range: Range<usize>, delta: i32) {
+        if !range.is_empty() {
+            self.add_to_node(1, 0..self.len, &range, delta);
+        }
+    }
+
+    fn add_to_node(
+        &mut self,
+        node: usize,
+        node_range: Range<usize>,
+        range: &Range<usize>,
+        delta: i32,
+    ) {
+        if range.end <= node_range.start || node_range.end <= range.start {
+            return;
+        }
+        if range.start <= node_range.start && node_range.end <= range.end {
+            self.added[node] += delta;
+            self.min[node] += delta;
+            return;
+        }
+        let mid = (node_range.start + node_range.end) / 2;
+        self.add_to_node(2 * node, node_range.start..mid, range, delta);
+        self.add_to_node(2 * node + 1, mid..node_range.end, range, delta);
+        self.min[node] = self.added[node] + self.min[2 * node].min(self.min[2 * node + 1]);
+    }
+
+    /// Maximal runs of intervals with a count of 0, in order.
+    fn uncovered(&self) -> Vec<Range<usize>> {
+        let mut uncovered = Vec::new();
+        if self.len > 0 {
+            self.uncovered_in_node(1, 0..self.len, &mut uncovered);
+        }
+        uncovered
+    }
+
+    fn uncovered_in_node(
+        &self,
+        node: usize,
+        node_range: Range<usize>,
+        uncovered: &mut Vec<Range<usize>>,
+    ) {
+        // Counts are never negative, so a node with a smallest count of 0 has nothing added to
+        // it, and neither do the ancestors it is reached through.
+        if self.min[node] > 0 {
+            return;
+        }
+        if node_range.len() == 1 {
+            match uncovered.last_mut() {
+                Some(last) if last.end == node_range.start => last.end = node_range.end,
+                _ => uncovered.push(node_range),
+            }
+            return;
+        }
+        let mid = (node_range.start + node_range.end) / 2;
+        self.uncovered_in_node(2 * node, node_range.start..mid, uncovered);
+        self.uncovered_in_node(2 * node + 1, mid..node_range.end, uncovered);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve, CoverCounts};
+    use crate::{generator, Sensor, Vec2};
+
+    #[test]
+    fn cover_counts() {
+        let mut counts = CoverCounts::new(10);
+        assert_eq!(counts.uncovered(), vec![0..10]);
+        counts.add(2..5, 1);
+        counts.add(4..7, 1);
+        assert_eq!(counts.uncovered(), vec![0..2, 7..10]);
+        counts.add(0..1, 1);
+        counts.add(2..5, -1);
+        assert_eq!(counts.uncovered(), vec![1..4, 7..10]);
+        counts.add(0..10, 1);
+        assert!(counts.uncovered().is_empty());
+    }
+
+    #[test]
+    fn thousands_of_sensors() {
+        let layout = generator::generate(1_000_000, 20_000, 5).unwrap();
+        assert_eq!(
+            solve(&layout.sensors, layout.search_size),
+            Some(layout.hole)
+        );
+    }
+
+    #[test]
+    fn search_area_of_one() {
+        let sensor = |x, y| Sensor {
+            id: 0,
+            pos: Vec2 { x, y },
+            range: 1,
+        };
+        assert_eq!(solve(&[], 0), Some(Vec2 { x: 0, y: 0 }));
+        assert_eq!(solve(&[sensor(1, 1)], 0), Some(Vec2 { x: 0, y: 0 }));
+        assert_eq!(solve(&[sensor(1, 0)], 0), None);
+    }
+}
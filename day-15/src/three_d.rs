@@ -0,0 +1,246 @@
+//! Sensors in three dimensions, whose ranges are octahedra, and a search cube from `(0, 0, 0)` to
+//! `(search_size, search_size, search_size)` inclusive.
+//!
+//! Each slice of the cube at a fixed z coordinate is an instance of the 2D puzzle: a sensor at a
+//! distance `dz` from the slice covers a diamond in it, with a range `dz` less than its own. So
+//! [`solve`] checks each slice with a [`Part2Solver`] in turn, which takes
+//! O(search_size * n log n) with [`SweepLine`]. Unlike intersecting the planes on the borders of
+//! the sensors' ranges, this finds the uncovered position however the sensors are laid out, but
+//! it is only practical for cubes of up to [`MAX_SLICES`] slices: at the puzzle's scale of
+//! 4,000,000 slices it would take hours, and [`is_practical`] is false.
+//!
+//! Inputs have the same format as the puzzle input, with a z coordinate after each y coordinate:
+//!
+//! ```text
+//! Sensor at x=2, y=18, z=4: closest beacon is at x=-2, y=15, z=3
+//! ```
+
+use aoc_common::{
+    error,
+    parse::{self, int, literal, Cursor},
+    ParseError,
+};
+
+use crate::{solver::Part2Solver, sweep_line::SweepLine, Coord, Sensor, Vec2};
+
+/// 3D position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+
+    /// Sum of the absolute differences of the coordinates.
+    pub fn manhattan_distance(self, other: Vec3<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+}
+
+/// Sensor in three dimensions, and the distance to its closest beacon.
+#[derive(Debug, Clone)]
+pub struct Sensor3<T = i32> {
+    id: usize,
+    pub pos: Vec3<T>,
+    pub range: T,
+}
+
+impl<T: Coord> Sensor3<T> {
+    pub fn new(id: usize, pos: Vec3<T>, range: T) -> Self {
+        Sensor3 { id, pos, range }
+    }
+
+    /// Line of the input the sensor was parsed from, or its index in a test case.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Whether `pos` is within range of the sensor.
+    pub fn covers(&self, pos: Vec3<T>) -> bool {
+        self.pos.manhattan_distance(pos) <= self.range
+    }
+
+    /// The diamond covered by the sensor in the slice at `z`, or `None` if its range doesn't reach
+    /// the slice.
+    pub fn slice(&self, z: T) -> Option<Sensor<T>> {
+        let range = self.range - (self.pos.z - z).abs();
+        (range >= T::ZERO).then(|| Sensor {
+            id: self.id,
+            pos: Vec2::new(self.pos.x, self.pos.y),
+            range,
+        })
+    }
+}
+
+/// Parses 3D sensors, with ranges measured to their closest beacons.
+pub fn parse(input: &str) -> Result<Vec<Sensor3>, ParseError> {
+    let mut sensors = Vec::new();
+    for line in error::lines(input) {
+        let (pos, beacon) = parse::parse_line(line, |cursor| {
+            literal("Sensor at ")(cursor)?;
+            let pos = parse_position(cursor)?;
+            literal(": closest beacon is at ")(cursor)?;
+            Ok((pos, parse_position(cursor)?))
+        })?;
+        sensors.push(Sensor3 {
+            id: line.number,
+            pos,
+            range: pos.manhattan_distance(beacon),
+        });
+    }
+    Ok(sensors)
+}
+
+/// Parses a position of the form "x=2, y=18, z=4".
+fn parse_position(cursor: &mut Cursor) -> Result<Vec3, ParseError> {
+    literal("x=")(cursor)?;
+    let x = int(cursor)?;
+    literal(", y=")(cursor)?;
+    let y = int(cursor)?;
+    literal(", z=")(cursor)?;
+    let z = int(cursor)?;
+    Ok(Vec3 { x, y, z })
+}
+
+/// Uncovered positions in the search cube, up to `limit` of them, ordered by x, then y, then z.
+/// Checks every position, so it is only practical for small cubes.
+pub fn brute_force<T: Coord>(sensors: &[Sensor3<T>], dimension: T, limit: usize) -> Vec<Vec3<T>> {
+    let mut uncovered = Vec::new();
+    if limit == 0 {
+        return uncovered;
+    }
+    let mut x = T::ZERO;
+    while x <= dimension {
+        let mut y = T::ZERO;
+        while y <= dimension {
+            let mut z = T::ZERO;
+            while z <= dimension {
+                let pos = Vec3 { x, y, z };
+                if !sensors.iter().any(|sensor| sensor.covers(pos)) {
+                    uncovered.push(pos);
+                    if uncovered.len() == limit {
+                        return uncovered;
+                    }
+                }
+                z += T::ONE;
+            }
+            y += T::ONE;
+        }
+        x += T::ONE;
+    }
+    uncovered
+}
+
+/// Number of slices of the search cube [`solve`] is expected to check in a reasonable time.
+pub const MAX_SLICES: i128 = 10_000;
+
+/// Whether [`solve`] is practical for a search cube of this size.
+pub fn is_practical<T: Coord>(search_size: T) -> bool {
+    search_size.to_i128() < MAX_SLICES
+}
+
+/// Returns `None` if there is no uncovered position in the search cube, or any one of them if
+/// there are several, checking each slice with [`SweepLine`]. Takes time proportional to the
+/// size of the cube, see [`is_practical`].
+pub fn solve<T: Coord>(sensors: &[Sensor3<T>], dimension: T) -> Option<Vec3<T>> {
+    solve_in_slices(&SweepLine, sensors, dimension)
+}
+
+/// An uncovered position in the search cube, found by checking the slices at each z coordinate
+/// with `solver`, from the lowest. Returns `None` if every position is covered.
+pub fn solve_in_slices<T: Coord>(
+    solver: &dyn Part2Solver<T>,
+    sensors: &[Sensor3<T>],
+    dimension: T,
+) -> Option<Vec3<T>> {
+    let mut z = T::ZERO;
+    while z <= dimension {
+        let slice: Vec<Sensor<T>> = sensors.iter().filter_map(|s| s.slice(z)).collect();
+        if let Some(pos) = solver.solve(&slice, dimension) {
+            return Some(Vec3::new(pos.x, pos.y, z));
+        }
+        z += T::ONE;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{brute_force, is_practical, parse, solve, Sensor3, Vec3, MAX_SLICES};
+
+    #[test]
+    fn parse_sensor() {
+        let sensors = parse(
+            "Sensor at x=2, y=18, z=4: closest beacon is at x=-2, y=15, z=3\n\
+             Sensor at x=0, y=0, z=0: closest beacon is at x=1, y=-1, z=-1\n",
+        )
+        .unwrap();
+        assert_eq!(sensors[0].pos, Vec3::new(2, 18, 4));
+        assert_eq!(sensors[0].range, 8);
+        assert_eq!(sensors[1].id(), 2);
+        assert_eq!(sensors[1].range, 3);
+        assert!(parse("Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n").is_err());
+    }
+
+    #[test]
+    fn slice() {
+        let sensor = Sensor3::new(0, Vec3::new(1, 2, 3), 4);
+        assert_eq!(sensor.slice(3).unwrap().range, 4);
+        assert_eq!(sensor.slice(1).unwrap().range, 2);
+        assert_eq!(sensor.slice(8).map(|s| s.range), None);
+        assert_eq!(sensor.slice(-1).unwrap().pos, crate::Vec2::new(1, 2));
+    }
+
+    /// A sensor in each corner of the search cube, whose ranges reach just short of `hole`,
+    /// covers every other position in the cube.
+    fn corners<T: crate::Coord>(size: T, hole: Vec3<T>) -> Vec<Sensor3<T>> {
+        let mut sensors = Vec::new();
+        for x in [T::ZERO, size] {
+            for y in [T::ZERO, size] {
+                for z in [T::ZERO, size] {
+                    let pos = Vec3::new(x, y, z);
+                    let range = pos.manhattan_distance(hole) - T::ONE;
+                    sensors.push(Sensor3::new(sensors.len(), pos, range));
+                }
+            }
+        }
+        sensors
+    }
+
+    #[test]
+    fn corner_sensors() {
+        for hole in [(0, 0, 0), (1, 2, 3), (4, 4, 4), (2, 3, 0)] {
+            let hole = Vec3::new(hole.0, hole.1, hole.2);
+            let sensors = corners(4, hole);
+            assert_eq!(brute_force(&sensors, 4, 2), [hole]);
+            assert_eq!(solve(&sensors, 4), Some(hole));
+        }
+        let hole = Vec3::new(123, 298, 250);
+        assert_eq!(solve(&corners(300i64, hole), 300), Some(hole));
+    }
+
+    #[test]
+    fn practical_sizes() {
+        assert!(is_practical(300));
+        assert!(is_practical(MAX_SLICES as i32 - 1));
+        assert!(!is_practical(MAX_SLICES as i32));
+        assert!(!is_practical(4_000_000i64));
+    }
+
+    #[test]
+    fn fully_covered() {
+        let sensors = [Sensor3::new(0, Vec3::new(1, 1, 1), 3)];
+        assert!(brute_force(&sensors, 2, 1).is_empty());
+        assert_eq!(brute_force(&sensors, 3, 4).len(), 4);
+        assert_eq!(brute_force(&sensors, 3, 0), []);
+        assert_eq!(solve(&sensors, 2), None);
+        let pos = solve(&sensors, 3).unwrap();
+        assert!(brute_force(&sensors, 3, usize::MAX).contains(&pos));
+        assert_eq!(pos.z, 0);
+    }
+}